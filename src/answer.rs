@@ -0,0 +1,93 @@
+use std::fmt;
+
+use crate::geom::Point;
+
+/// A part's answer. Its `Display` form is the canonical text to submit, which
+/// the runner, verifier and submitter all compare and record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    /// A position, written `x,y`.
+    Coord(Point),
+    /// Numbers written as a comma separated list, e.g. `4,6,3`.
+    List(Vec<i128>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            Answer::Str(value) => write!(f, "{value}"),
+            Answer::Coord(point) => write!(f, "{},{}", point.x, point.y),
+            Answer::List(values) => {
+                for (idx, value) in values.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+impl From<Point> for Answer {
+    fn from(point: Point) -> Self {
+        Answer::Coord(point)
+    }
+}
+
+impl<T: Into<i128>> FromIterator<T> for Answer {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Answer::List(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(11u64).to_string(), "11");
+        assert_eq!(Answer::from(-3i32).to_string(), "-3");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::from(Point::new(6, 1)).to_string(), "6,1");
+        assert_eq!([4u8, 6, 3].into_iter().collect::<Answer>().to_string(), "4,6,3");
+    }
+}
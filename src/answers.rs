@@ -0,0 +1,137 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Directory holding the known answers for each day's puzzle input.
+pub const ANSWER_DIR: &str = "answers";
+
+/// Recorded answers for one day's input, stored as `part1: <answer>` and
+/// `part2: <answer>` lines. Either part may be absent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Verdict::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() { continue }
+
+            let Some((key, value)) = line.split_once(':') else {
+                return Err(format!("line {}: expected `part1: <answer>` or `part2: <answer>`", idx+1))
+            };
+            let value = Some(value.trim().to_string());
+            match key.trim() {
+                "part1" => answers.part1 = value,
+                "part2" => answers.part2 = value,
+                key => return Err(format!("line {}: unknown key `{key}`", idx+1)),
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            2 => self.part2 = Some(answer),
+            _ => panic!("Invalid part {part}"),
+        }
+    }
+
+    pub fn check(&self, part: u8, answer: &str) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Missing,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_string() },
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(part1) = &self.part1 {
+            writeln!(f, "part1: {part1}")?;
+        }
+        if let Some(part2) = &self.part2 {
+            writeln!(f, "part2: {part2}")?;
+        }
+        Ok(())
+    }
+}
+
+
+pub fn path(day: u32) -> PathBuf {
+    Path::new(ANSWER_DIR).join(format!("day_{day}.txt"))
+}
+
+
+/// Loads the recorded answers for a day, treating a missing file as having
+/// no answers yet.
+pub fn load(day: u32) -> io::Result<Answers> {
+    let contents = match std::fs::read_to_string(path(day)) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+        Err(err) => return Err(err),
+    };
+    Answers::parse(&contents)
+        .map_err(|msg| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {msg}", path(day).display())))
+}
+
+
+pub fn save(day: u32, answers: &Answers) -> io::Result<()> {
+    std::fs::create_dir_all(ANSWER_DIR)?;
+    std::fs::write(path(day), answers.to_string())
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("part1: 4,6,3\n\npart2: 117440\n").unwrap();
+        assert_eq!(answers.get(1), Some("4,6,3"));
+        assert_eq!(answers.get(2), Some("117440"));
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+
+        assert!(Answers::parse("part3: 1").is_err());
+        assert!(Answers::parse("42").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("part1: 11").unwrap();
+        assert_eq!(answers.check(1, "11"), Verdict::Pass);
+        assert_eq!(answers.check(1, "12"), Verdict::Fail { expected: "11".to_string() });
+        assert_eq!(answers.check(2, "31"), Verdict::Missing);
+    }
+}
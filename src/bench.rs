@@ -0,0 +1,235 @@
+use std::fmt::Write;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::report::format_ns;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Untimed runs before sampling starts, to warm caches and the allocator.
+    pub warmup: u32,
+    pub samples: u32,
+}
+
+/// Summary statistics over a set of timing samples, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: u32,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [u64]) -> Self {
+        assert!(!samples.is_empty(), "No samples to summarise");
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let median_ns = if n % 2 == 1 {
+            samples[n/2]
+        } else {
+            (samples[n/2 - 1] + samples[n/2]) / 2
+        };
+        let mean_ns = samples.iter().map(|&s| s as f64).sum::<f64>() / n as f64;
+        let variance = samples.iter()
+            .map(|&s| (s as f64 - mean_ns).powi(2))
+            .sum::<f64>() / n as f64;
+
+        Self {
+            samples: n as u32,
+            min_ns: samples[0],
+            median_ns,
+            mean_ns,
+            stddev_ns: variance.sqrt(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u32,
+    pub parse: Stats,
+    pub parts: Vec<PartBench>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartBench {
+    pub part: u8,
+    pub stats: Stats,
+}
+
+impl DayBench {
+    /// Every measured step of the day, labelled `parse`, `1` and `2`.
+    fn steps(&self) -> impl Iterator<Item = (String, &Stats)> {
+        std::iter::once(("parse".to_string(), &self.parse))
+            .chain(self.parts.iter().map(|p| (p.part.to_string(), &p.stats)))
+    }
+}
+
+/// A step whose median time grew by more than the allowed threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u32,
+    pub step: String,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+}
+
+impl Regression {
+    pub fn change_pct(&self) -> f64 {
+        percent_change(self.baseline_ns, self.current_ns)
+    }
+}
+
+
+/// Times `f` over `config.samples` runs after `config.warmup` untimed ones.
+pub fn sample<T>(config: BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let mut samples = (0..config.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed().as_nanos().try_into().unwrap_or(u64::MAX)
+        })
+        .collect::<Vec<u64>>();
+
+    Stats::from_samples(&mut samples)
+}
+
+
+/// Compares medians against a baseline, returning every step that slowed
+/// down by more than `threshold_pct` percent. Steps missing from the
+/// baseline are ignored.
+pub fn compare(current: &[DayBench], baseline: &[DayBench], threshold_pct: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+    for day in current {
+        let Some(base_day) = baseline.iter().find(|b| b.day == day.day) else { continue };
+        for ((step, stats), (_, base_stats)) in day.steps().zip(base_day.steps()) {
+            if percent_change(base_stats.median_ns, stats.median_ns) > threshold_pct {
+                regressions.push(Regression {
+                    day: day.day,
+                    step,
+                    baseline_ns: base_stats.median_ns,
+                    current_ns: stats.median_ns,
+                });
+            }
+        }
+    }
+    regressions
+}
+
+
+pub fn load_baseline(path: &Path) -> io::Result<Vec<DayBench>> {
+    let contents = std::fs::read_to_string(path)?;
+    serde_json::from_str(&contents)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+
+pub fn save_baseline(path: &Path, benches: &[DayBench]) -> io::Result<()> {
+    std::fs::write(path, serde_json::to_string_pretty(benches).unwrap())
+}
+
+
+/// Renders the results as a table, with each step's median change against
+/// the baseline when one is given.
+pub fn to_table(benches: &[DayBench], baseline: Option<&[DayBench]>) -> String {
+    let mut out = String::new();
+    write!(out, "{:>3}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}", "Day", "Step", "Min", "Median", "Mean", "Std dev").unwrap();
+    if baseline.is_some() {
+        write!(out, "  {:>10}  {:>8}", "Baseline", "Change").unwrap();
+    }
+    out.push('\n');
+
+    for day in benches {
+        let base_day = baseline.and_then(|b| b.iter().find(|b| b.day == day.day));
+        let base_steps = base_day.map(|b| b.steps().collect::<Vec<_>>()).unwrap_or_default();
+
+        for (idx, (step, stats)) in day.steps().enumerate() {
+            write!(
+                out,
+                "{:>3}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}",
+                day.day,
+                step,
+                format_ns(stats.min_ns),
+                format_ns(stats.median_ns),
+                format_ns(stats.mean_ns as u64),
+                format_ns(stats.stddev_ns as u64),
+            ).unwrap();
+
+            if baseline.is_some() {
+                match base_steps.get(idx) {
+                    Some((_, base)) => write!(
+                        out,
+                        "  {:>10}  {:>+7.1}%",
+                        format_ns(base.median_ns),
+                        percent_change(base.median_ns, stats.median_ns)
+                    ).unwrap(),
+                    None => write!(out, "  {:>10}  {:>8}", "-", "-").unwrap(),
+                }
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+
+fn percent_change(baseline_ns: u64, current_ns: u64) -> f64 {
+    if baseline_ns == 0 {
+        return 0.0
+    }
+    (current_ns as f64 - baseline_ns as f64) / baseline_ns as f64 * 100.0
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats { samples: 1, min_ns: median_ns, median_ns, mean_ns: median_ns as f64, stddev_ns: 0.0 }
+    }
+
+    fn day_bench(day: u32, parse: u64, p1: u64, p2: u64) -> DayBench {
+        DayBench {
+            day,
+            parse: stats(parse),
+            parts: vec![
+                PartBench { part: 1, stats: stats(p1) },
+                PartBench { part: 2, stats: stats(p2) },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&mut [5, 1, 3, 7]);
+        assert_eq!(stats.min_ns, 1);
+        assert_eq!(stats.median_ns, 4);
+        assert_eq!(stats.mean_ns, 4.0);
+        assert_eq!(stats.stddev_ns, 5.0_f64.sqrt());
+
+        let stats = Stats::from_samples(&mut [9, 2, 4]);
+        assert_eq!(stats.median_ns, 4);
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = vec![day_bench(6, 100, 1000, 5000), day_bench(18, 100, 100, 100)];
+        let current = vec![day_bench(6, 105, 1200, 4000), day_bench(20, 100, 100, 100)];
+
+        let regressions = compare(&current, &baseline, 10.0);
+        assert_eq!(regressions, vec![Regression { day: 6, step: "1".to_string(), baseline_ns: 1000, current_ns: 1200 }]);
+        assert_eq!(regressions[0].change_pct(), 20.0);
+    }
+}
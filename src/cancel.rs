@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+use std::panic;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Stack size for the thread a budgeted part runs on, matching the main
+/// thread so recursive solvers behave the same either way.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// The unwind payload [`check`] stops a cancelled part with.
+#[derive(Debug)]
+pub struct Cancelled;
+
+thread_local! {
+    static TOKEN: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+
+/// Whether the part running on this thread has used up its time budget.
+pub fn is_cancelled() -> bool {
+    TOKEN.with_borrow(|token| token.as_ref().is_some_and(|token| token.load(Ordering::Relaxed)))
+}
+
+
+/// Stops the current part if it has used up its time budget. Solvers call
+/// this in loops that could run away on unexpected input; outside
+/// [`with_budget`] it never stops anything.
+pub fn check() {
+    if is_cancelled() {
+        // Unlike `panic!`, this skips the panic hook, so nothing is printed
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+
+/// Runs `f` on its own thread, cancelling it once `budget` has passed.
+/// Returns `None` if it was cancelled. A part that never calls [`check`]
+/// still runs to completion.
+pub fn with_budget<T: Send>(budget: Duration, f: impl FnOnce() -> T + Send) -> Option<T> {
+    let token = Arc::new(AtomicBool::new(false));
+
+    thread::scope(|scope| {
+        let (done_tx, done_rx) = mpsc::channel();
+        let thread_token = token.clone();
+        let handle = thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn_scoped(scope, move || {
+                TOKEN.set(Some(thread_token));
+                let result = f();
+                let _ = done_tx.send(());
+                result
+            })
+            .expect("Failed to spawn solver thread");
+
+        if done_rx.recv_timeout(budget).is_err() {
+            token.store(true, Ordering::Relaxed);
+        }
+
+        match handle.join() {
+            Ok(result) => Some(result),
+            Err(payload) if payload.is::<Cancelled>() => None,
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    })
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_budget() {
+        assert_eq!(with_budget(Duration::from_secs(10), || 42), Some(42));
+
+        let result = with_budget(Duration::from_millis(20), || loop {
+            check();
+            thread::sleep(Duration::from_millis(1));
+        });
+        assert_eq!(result, None::<()>);
+
+        check();
+        assert!(!is_cancelled());
+    }
+}
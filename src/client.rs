@@ -0,0 +1,353 @@
+use std::cell::Cell;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::config::Config;
+
+/// The puzzle year every request is made for.
+pub const YEAR: u32 = 2024;
+
+/// Minimum gap between two requests from one run of the tool.
+pub const DEFAULT_RATE_LIMIT: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = concat!("aoc2024-runner/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The HTTP requests the client needs, so tests can substitute a fake.
+pub trait Http {
+    fn get(&self, url: &str, cookie: &str) -> Result<HttpResponse, String>;
+
+    fn post_form(&self, url: &str, cookie: &str, form: &[(&str, &str)]) -> Result<HttpResponse, String>;
+}
+
+/// The real HTTP backend.
+pub struct UreqHttp {
+    agent: ureq::Agent,
+}
+
+impl UreqHttp {
+    pub fn new() -> Self {
+        let config = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build();
+        Self { agent: ureq::Agent::new_with_config(config) }
+    }
+}
+
+impl Default for UreqHttp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Http for UreqHttp {
+    fn get(&self, url: &str, cookie: &str) -> Result<HttpResponse, String> {
+        let response = self.agent.get(url)
+            .header("Cookie", cookie)
+            .call();
+        read_response(response)
+    }
+
+    fn post_form(&self, url: &str, cookie: &str, form: &[(&str, &str)]) -> Result<HttpResponse, String> {
+        let response = self.agent.post(url)
+            .header("Cookie", cookie)
+            .send_form(form.iter().copied());
+        read_response(response)
+    }
+}
+
+fn read_response(response: Result<ureq::http::Response<ureq::Body>, ureq::Error>) -> Result<HttpResponse, String> {
+    let mut response = response.map_err(|err| err.to_string())?;
+    let status = response.status().as_u16();
+    let body = response.body_mut().read_to_string().map_err(|err| err.to_string())?;
+    Ok(HttpResponse { status, body })
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session token in the config file or environment.
+    NoSession,
+    Status { url: String, status: u16 },
+    Transport { url: String, message: String },
+    Io(io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NoSession => {
+                write!(f, "no session token (set AOC_SESSION or `session` in {})", crate::config::CONFIG_PATH)
+            },
+            ClientError::Status { url, status } => {
+                write!(f, "{url} returned HTTP {status}")?;
+                match status {
+                    400 | 401 | 403 => write!(f, " (is the session token still valid?)"),
+                    404 => write!(f, " (is the puzzle unlocked yet?)"),
+                    _ => Ok(()),
+                }
+            },
+            ClientError::Transport { url, message } => write!(f, "request to {url} failed: {message}"),
+            ClientError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        ClientError::Io(err)
+    }
+}
+
+/// An Advent of Code client that spaces out its requests.
+pub struct Client<H = UreqHttp> {
+    http: H,
+    base_url: String,
+    session: Option<String>,
+    rate_limit: Duration,
+    last_request: Cell<Option<Instant>>,
+}
+
+impl Client {
+    pub fn from_config(config: &Config) -> Self {
+        Client::new(UreqHttp::new(), config.base_url(), config.session.clone())
+    }
+}
+
+impl<H: Http> Client<H> {
+    pub fn new(http: H, base_url: &str, session: Option<String>) -> Self {
+        Self {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            rate_limit: DEFAULT_RATE_LIMIT,
+            last_request: Cell::new(None),
+        }
+    }
+
+    pub fn with_rate_limit(self, rate_limit: Duration) -> Self {
+        Self { rate_limit, ..self }
+    }
+
+    pub fn http(&self) -> &H {
+        &self.http
+    }
+
+    /// Downloads a day's puzzle input.
+    pub fn download_input(&self, day: u32) -> Result<String, ClientError> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        self.request(&url, |http, cookie| http.get(&url, cookie))
+    }
+
+    /// Posts an answer, returning the page the site replies with.
+    pub fn submit_answer(&self, day: u32, part: u8, answer: &str) -> Result<String, ClientError> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        self.request(&url, |http, cookie| http.post_form(&url, cookie, &form))
+    }
+
+    /// Sends one rate-limited request, turning anything but a 200 into an
+    /// error.
+    fn request(
+        &self,
+        url: &str,
+        send: impl FnOnce(&H, &str) -> Result<HttpResponse, String>,
+    ) -> Result<String, ClientError> {
+        let session = self.session.as_deref().ok_or(ClientError::NoSession)?;
+
+        if let Some(last) = self.last_request.get() {
+            std::thread::sleep(self.rate_limit.saturating_sub(last.elapsed()));
+        }
+        let response = send(&self.http, &format!("session={session}"));
+        self.last_request.set(Some(Instant::now()));
+
+        let response = response
+            .map_err(|message| ClientError::Transport { url: url.to_string(), message })?;
+        if response.status != 200 {
+            return Err(ClientError::Status { url: url.to_string(), status: response.status })
+        }
+        Ok(response.body)
+    }
+}
+
+/// Where `fetch` found a day's input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// Already on disk, so nothing was downloaded.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+
+/// Makes sure a day's input is saved as `day_N.txt` in `dir`, downloading
+/// it only if it isn't there already.
+pub fn fetch<H: Http>(client: &Client<H>, dir: &Path, day: u32) -> Result<Fetched, ClientError> {
+    let path = dir.join(format!("day_{day}.txt"));
+    if path.exists() {
+        return Ok(Fetched::Cached(path))
+    }
+
+    let input = client.download_input(day)?;
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(&path, input)?;
+    Ok(Fetched::Downloaded(path))
+}
+
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::cell::RefCell;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Request {
+        pub url: String,
+        pub cookie: String,
+        pub form: Vec<(String, String)>,
+    }
+
+    /// Records each request and replies with canned responses in order.
+    #[derive(Default)]
+    pub struct FakeHttp {
+        pub requests: RefCell<Vec<Request>>,
+        pub responses: RefCell<Vec<HttpResponse>>,
+    }
+
+    impl FakeHttp {
+        pub fn replying(responses: &[(u16, &str)]) -> Self {
+            Self {
+                requests: RefCell::default(),
+                responses: RefCell::new(responses.iter()
+                    .map(|&(status, body)| HttpResponse { status, body: body.to_string() })
+                    .collect()),
+            }
+        }
+
+        fn reply(&self, url: &str, cookie: &str, form: &[(&str, &str)]) -> Result<HttpResponse, String> {
+            let form = form.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect();
+            self.requests.borrow_mut().push(Request { url: url.to_string(), cookie: cookie.to_string(), form });
+            let mut responses = self.responses.borrow_mut();
+            if responses.is_empty() {
+                return Err("no more canned responses".to_string())
+            }
+            Ok(responses.remove(0))
+        }
+    }
+
+    impl Http for FakeHttp {
+        fn get(&self, url: &str, cookie: &str) -> Result<HttpResponse, String> {
+            self.reply(url, cookie, &[])
+        }
+
+        fn post_form(&self, url: &str, cookie: &str, form: &[(&str, &str)]) -> Result<HttpResponse, String> {
+            self.reply(url, cookie, form)
+        }
+    }
+
+    /// A one-shot HTTP server on localhost. Returns its base URL and a
+    /// handle yielding the raw request it received.
+    pub fn stub_server(status: u16, body: &'static str) -> (String, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() { break }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            ).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc2024-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_caches() {
+        let dir = temp_dir("fetch");
+        let client = Client::new(FakeHttp::replying(&[(200, "1 2\n")]), "http://aoc/", Some("abc".to_string()));
+
+        let fetched = fetch(&client, &dir, 3).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(dir.join("day_3.txt")));
+        assert_eq!(std::fs::read_to_string(dir.join("day_3.txt")).unwrap(), "1 2\n");
+        assert_eq!(fetch(&client, &dir, 3).unwrap(), Fetched::Cached(dir.join("day_3.txt")));
+
+        let requests = client.http().requests.borrow();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "http://aoc/2024/day/3/input");
+        assert_eq!(requests[0].cookie, "session=abc");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_errors() {
+        let dir = temp_dir("errors");
+        let client = Client::new(FakeHttp::replying(&[(404, "")]), "http://aoc", None);
+        assert!(matches!(fetch(&client, &dir, 1), Err(ClientError::NoSession)));
+
+        let client = Client::new(FakeHttp::replying(&[(404, "")]), "http://aoc", Some("abc".to_string()));
+        assert!(matches!(fetch(&client, &dir, 1), Err(ClientError::Status { status: 404, .. })));
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let client = Client::new(FakeHttp::replying(&[(200, ""), (200, "")]), "http://aoc", Some("abc".to_string()))
+            .with_rate_limit(Duration::from_millis(50));
+
+        let start = Instant::now();
+        client.download_input(1).unwrap();
+        client.download_input(2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_stub_server() {
+        let (base_url, server) = stub_server(200, "3   4\n");
+        let client = Client::new(UreqHttp::new(), &base_url, Some("abc".to_string()));
+
+        assert_eq!(client.download_input(1).unwrap(), "3   4\n");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(request.to_ascii_lowercase().contains("cookie: session=abc"));
+    }
+}
@@ -0,0 +1,271 @@
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::Solution;
+use crate::cancel;
+use crate::generate::Rng;
+use crate::report::{format_bytes, format_ns};
+
+/// How far to grow the inputs, and how to time each size.
+#[derive(Debug, Clone)]
+pub struct ComplexityConfig {
+    pub seed: u64,
+    /// The largest generator scale to try, doubling from 1.
+    pub max_scale: u32,
+    /// Timed runs per size, of which the fastest is kept.
+    pub samples: u32,
+    /// A step stops growing once one run takes longer than this.
+    pub limit: Duration,
+    /// A run still going after this long is stopped, which ends its step.
+    pub budget: Duration,
+    /// Parameter overrides, applied after those of each generated input.
+    pub overrides: Vec<(String, i64)>,
+}
+
+impl Default for ComplexityConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            max_scale: 64,
+            samples: 3,
+            limit: Duration::from_secs(1),
+            budget: Duration::from_secs(10),
+            overrides: vec![],
+        }
+    }
+}
+
+
+/// One step run on one input size.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub scale: u32,
+    pub input_bytes: usize,
+    /// The fastest of the timed runs.
+    pub time_ns: u64,
+    /// How far the first run raised the process's peak resident memory
+    /// above what it was using beforehand. `None` where that can't be read.
+    /// Memory the allocator kept from earlier runs can make this an
+    /// underestimate.
+    pub peak_bytes: Option<u64>,
+}
+
+/// How one step, `parse`, `1` or `2`, grew with its input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Growth {
+    pub step: String,
+    pub measurements: Vec<Measurement>,
+    /// Whether the step stopped early by running out of time budget.
+    pub timed_out: bool,
+}
+
+impl Growth {
+    fn new(step: &str) -> Self {
+        Self { step: step.to_string(), measurements: vec![], timed_out: false }
+    }
+
+    /// The apparent `k` in time ≈ c·nᵏ for an input of n bytes, fitted over
+    /// the larger half of the sizes where fixed costs matter least.
+    pub fn time_exponent(&self) -> Option<f64> {
+        fit_exponent(&self.tail(|m| Some(m.time_ns)))
+    }
+
+    /// Like [`Growth::time_exponent`], for peak memory.
+    pub fn memory_exponent(&self) -> Option<f64> {
+        fit_exponent(&self.tail(|m| m.peak_bytes))
+    }
+
+    fn tail(&self, value: impl Fn(&Measurement) -> Option<u64>) -> Vec<(f64, f64)> {
+        let start = (self.measurements.len() / 2).min(self.measurements.len().saturating_sub(2));
+        self.measurements[start..].iter()
+            .filter_map(|m| Some((m.input_bytes as f64, value(m)? as f64)))
+            .collect()
+    }
+}
+
+
+/// Runs the parse and both parts on generated inputs of doubling scale,
+/// timing each and reading its peak memory, until the step gets slower than
+/// `config.limit` or the scale passes `config.max_scale`.
+pub fn profile<S: Solution>(config: &ComplexityConfig) -> Result<Vec<Growth>, String> {
+    let mut steps = [Growth::new("parse"), Growth::new("1"), Growth::new("2")];
+    let mut growing = [true; 3];
+
+    let mut scale = 1;
+    while scale <= config.max_scale && growing.contains(&true) {
+        let generated = S::generate(&mut Rng::new(config.seed), scale)
+            .ok_or("no input generator")?;
+        let overrides = config.overrides.iter().map(|(name, value)| (name.as_str(), *value));
+        let params = S::default_params()
+            .with_overrides(generated.params.iter().copied())
+            .and_then(|params| params.with_overrides(overrides))?;
+        let input = generated.input.as_str();
+        let parsed = S::parse(input).map_err(|err| format!("generated input at scale {scale} doesn't parse: {err}"))?;
+
+        for (idx, growth) in steps.iter_mut().enumerate() {
+            if !growing[idx] { continue }
+            let measured = match idx {
+                0 => measure(config, || S::parse(input)),
+                1 => measure(config, || S::part1(&parsed, &params)),
+                _ => measure(config, || S::part2(&parsed, &params)),
+            };
+
+            match measured {
+                Some((time_ns, peak_bytes)) => {
+                    growth.measurements.push(Measurement { scale, input_bytes: input.len(), time_ns, peak_bytes });
+                    growing[idx] = Duration::from_nanos(time_ns) <= config.limit;
+                },
+                None => {
+                    growth.timed_out = true;
+                    growing[idx] = false;
+                },
+            }
+        }
+
+        scale *= 2;
+    }
+
+    Ok(steps.into())
+}
+
+
+/// The fastest of `config.samples` runs of `f` and the peak memory of the
+/// first, or `None` if a run used up the time budget.
+fn measure<T>(config: &ComplexityConfig, f: impl Fn() -> T + Sync) -> Option<(u64, Option<u64>)> {
+    let mut fastest = u64::MAX;
+    let mut peak_bytes = None;
+
+    for sample in 0..config.samples.max(1) {
+        let before = peak_rss::reset();
+        // Timed on the budget thread itself, leaving out spawning it
+        let elapsed = cancel::with_budget(config.budget, || {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })?;
+
+        fastest = fastest.min(elapsed.as_nanos().try_into().unwrap_or(u64::MAX));
+        if sample == 0 {
+            peak_bytes = before.zip(peak_rss::read()).map(|(before, peak)| peak.saturating_sub(before));
+        }
+    }
+
+    Some((fastest, peak_bytes))
+}
+
+
+/// The slope of the least-squares line through the points on log-log axes:
+/// the `k` in y ≈ c·xᵏ. `None` unless there are two distinct positive x
+/// values to fit.
+pub fn fit_exponent(points: &[(f64, f64)]) -> Option<f64> {
+    let logs = points.iter()
+        .filter(|&&(x, y)| x > 0.0 && y > 0.0)
+        .map(|&(x, y)| (x.ln(), y.ln()))
+        .collect::<Vec<_>>();
+    let n = logs.len() as f64;
+    let mean_x = logs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = logs.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let covariance = logs.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum::<f64>();
+    let variance = logs.iter().map(|(x, _)| (x - mean_x).powi(2)).sum::<f64>();
+    (logs.len() >= 2 && variance > 1e-9).then(|| covariance / variance)
+}
+
+
+/// Renders each step's measurements followed by its fitted exponents.
+pub fn to_table(growths: &[Growth]) -> String {
+    let mut out = String::new();
+    writeln!(out, "{:>5}  {:>5}  {:>10}  {:>10}  {:>10}", "Step", "Scale", "Input", "Time", "Peak mem").unwrap();
+    for growth in growths {
+        for m in growth.measurements.iter() {
+            writeln!(
+                out,
+                "{:>5}  {:>5}  {:>10}  {:>10}  {:>10}",
+                growth.step,
+                m.scale,
+                format_bytes(m.input_bytes as u64),
+                format_ns(m.time_ns),
+                m.peak_bytes.map_or_else(|| "-".to_string(), format_bytes),
+            ).unwrap();
+        }
+        if growth.timed_out {
+            writeln!(out, "{:>5}  {:>5}  {:>10}  {:>10}", growth.step, "-", "-", "timed out").unwrap();
+        }
+    }
+
+    out.push('\n');
+    for growth in growths {
+        writeln!(
+            out,
+            "{:>5}  time ~ {}, memory ~ {}",
+            growth.step,
+            format_exponent(growth.time_exponent()),
+            format_exponent(growth.memory_exponent()),
+        ).unwrap();
+    }
+    out
+}
+
+
+fn format_exponent(exponent: Option<f64>) -> String {
+    exponent.map_or_else(|| "n/a".to_string(), |k| format!("n^{k:.2}"))
+}
+
+
+/// The process's peak resident memory, read from `/proc` on Linux.
+mod peak_rss {
+    /// Resets the peak to the memory in use now and returns that amount.
+    pub fn reset() -> Option<u64> {
+        std::fs::write("/proc/self/clear_refs", "5").ok()?;
+        status_bytes("VmRSS")
+    }
+
+    pub fn read() -> Option<u64> {
+        status_bytes("VmHWM")
+    }
+
+    fn status_bytes(field: &str) -> Option<u64> {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let kib = status.lines()
+            .find_map(|line| line.strip_prefix(field)?.strip_prefix(':'))?
+            .trim()
+            .strip_suffix("kB")?
+            .trim()
+            .parse::<u64>()
+            .ok()?;
+        Some(kib * 1024)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fit_exponent() {
+        let quadratic = (1..=5).map(|x| (x as f64 * 100.0, 3.0 * (x as f64 * 100.0).powi(2))).collect::<Vec<_>>();
+        assert!((fit_exponent(&quadratic).unwrap() - 2.0).abs() < 1e-9);
+
+        let linear = [(10.0, 5.0), (20.0, 10.0), (40.0, 20.0)];
+        assert!((fit_exponent(&linear).unwrap() - 1.0).abs() < 1e-9);
+
+        assert_eq!(fit_exponent(&[(10.0, 5.0)]), None);
+        assert_eq!(fit_exponent(&[(10.0, 5.0), (10.0, 7.0)]), None);
+    }
+
+    #[test]
+    fn test_growth() {
+        let growth = Growth {
+            step: "1".to_string(),
+            measurements: [(1, 50, 900), (2, 100, 100), (4, 200, 400), (8, 400, 1600)].into_iter()
+                .map(|(scale, input_bytes, time_ns)| Measurement { scale, input_bytes, time_ns, peak_bytes: None })
+                .collect(),
+            timed_out: false,
+        };
+        // The smaller sizes, where fixed costs dominate, are left out of the fit
+        assert!((growth.time_exponent().unwrap() - 2.0).abs() < 1e-9);
+        assert_eq!(growth.memory_exponent(), None);
+    }
+}
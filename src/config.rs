@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// Optional settings file, read from the working directory.
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings from `aoc.toml`, with the `AOC_SESSION` and `AOC_BASE_URL`
+/// environment variables taking precedence over the file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The `session` cookie of a logged in Advent of Code account.
+    pub session: Option<String>,
+    /// Where to fetch inputs from, overridable to point at a local server.
+    pub base_url: Option<String>,
+    /// Puzzle parameter overrides, as `[params.dayN]` tables of `name = value`.
+    pub params: BTreeMap<String, BTreeMap<String, i64>>,
+}
+
+impl Config {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        toml::from_str(s).map_err(|err| err.to_string())
+    }
+
+    /// Overrides file settings with any set in the environment, looked up
+    /// through `var`.
+    pub fn apply_env(mut self, var: impl Fn(&str) -> Option<String>) -> Self {
+        if let Some(session) = var("AOC_SESSION") {
+            self.session = Some(session);
+        }
+        if let Some(base_url) = var("AOC_BASE_URL") {
+            self.base_url = Some(base_url);
+        }
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL)
+    }
+
+    /// The parameter overrides set for `day`.
+    pub fn params(&self, day: u32) -> impl Iterator<Item = (&str, i64)> {
+        self.params.get(&format!("day{day}"))
+            .into_iter()
+            .flatten()
+            .map(|(name, &value)| (name.as_str(), value))
+    }
+}
+
+
+/// Loads [`CONFIG_PATH`] if it exists, then applies the environment.
+pub fn load() -> io::Result<Config> {
+    load_from(Path::new(CONFIG_PATH))
+}
+
+
+pub fn load_from(path: &Path) -> io::Result<Config> {
+    let config = match std::fs::read_to_string(path) {
+        Ok(contents) => Config::parse(&contents)
+            .map_err(|msg| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {msg}", path.display())))?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Config::default(),
+        Err(err) => return Err(err),
+    };
+    Ok(config.apply_env(|var| std::env::var(var).ok().filter(|val| !val.is_empty())))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse("session = \"abc\"\n").unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url(), DEFAULT_BASE_URL);
+
+        assert!(Config::parse("sesion = \"abc\"").is_err());
+    }
+
+    #[test]
+    fn test_env() {
+        let config = Config::parse("session = \"abc\"\nbase_url = \"http://file\"").unwrap()
+            .apply_env(|var| (var == "AOC_SESSION").then(|| "env".to_string()));
+        assert_eq!(config.session.as_deref(), Some("env"));
+        assert_eq!(config.base_url(), "http://file");
+    }
+
+    #[test]
+    fn test_params() {
+        let config = Config::parse("[params.day14]\nwidth = 11\nheight = 7\n").unwrap();
+        assert_eq!(config.params(14).collect::<Vec<_>>(), [("height", 7), ("width", 11)]);
+        assert_eq!(config.params(18).count(), 0);
+    }
+}
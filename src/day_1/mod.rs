@@ -0,0 +1,131 @@
+use std::iter::zip;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use nom::{
+    character::complete::{digit1, multispace1},
+    combinator::map_res,
+    sequence::separated_pair,
+    IResult,
+};
+use itertools::multiunzip;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = (Vec<u32>, Vec<u32>);
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("11").part2("31"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+}
+
+
+fn p1((left_list, right_list): &(Vec<u32>, Vec<u32>)) -> Answer {
+    let mut left_list = left_list.clone();
+    let mut right_list = right_list.clone();
+
+    left_list.sort();
+    right_list.sort();
+
+    let tot_dist = zip(left_list, right_list)
+        .map(|(l, r)| u32::abs_diff(l, r))
+        .sum::<u32>();
+
+
+    tot_dist.into()
+}
+
+
+fn p2((left_list, right_list): &(Vec<u32>, Vec<u32>)) -> Answer {
+    let mut right_counts: HashMap::<u32, u32> = HashMap::new();
+
+    right_list.iter()
+        .for_each(|val| {
+            let count = right_counts.entry(*val).or_insert(0);
+            *count += 1;
+        });
+    
+    let score = left_list.iter()
+        .map(|val| {
+            let count = right_counts.get(val).unwrap_or(&0);
+            (*val) * (*count)
+        })
+        .sum::<u32>();
+    
+    score.into()
+}
+
+
+/// Two columns of location ids, with about half of those on the right copied
+/// from the left so part 2 has similarities to find.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let left = (0..6 * scale).map(|_| rng.range(10000..100000)).collect::<Vec<_>>();
+    let mut out = String::new();
+    for &id in left.iter() {
+        let other = if rng.one_in(2) { *rng.pick(&left) } else { rng.range(10000..100000) };
+        writeln!(out, "{id}   {other}").unwrap();
+    }
+    out
+}
+
+
+fn parse_input(input: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let pairs = input.lines()
+        .map(|line| parse_line(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(multiunzip(pairs))
+}
+
+
+fn parse_line(input: &str, line: &str) -> Result<(u32, u32), ParseError> {
+    let result = separated_pair(
+        parse_number, 
+        multispace1, 
+        parse_number
+    )(line);
+    parse::finish(input, result)
+}
+
+
+fn parse_number(input: &str) -> IResult<&str, u32> {
+    map_res(digit1, |s: &str| s.parse::<u32>())(input)
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day1>();
+    }
+
+}
\ No newline at end of file
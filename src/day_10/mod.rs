@@ -0,0 +1,147 @@
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::grid::{Grid, Idx};
+use crate::search::{bfs, Search};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Grid<u8>;
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("36").part2("81"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+}
+
+
+fn p1(grid: &Grid<u8>) -> Answer {
+    let score = grid.positions(|&val| val == 0)
+        .map(|pos| trailhead_score(grid, pos))
+        .sum::<u32>();
+    
+    score.into()
+}
+
+
+fn p2(grid: &Grid<u8>) -> Answer {
+    let score = grid.positions(|&val| val == 0)
+        .map(|pos| trailhead_rating(grid, pos))
+        .sum::<u32>();
+    
+    score.into()
+}
+
+
+fn trailhead_score(grid: &Grid<u8>, starting_pos: Idx) -> u32 {
+    let search = walk_trails(grid, starting_pos);
+    search.reached()
+        .filter(|&&pos| grid[pos] == 9)
+        .count() as u32
+}
+
+
+fn trailhead_rating(grid: &Grid<u8>, starting_pos: Idx) -> u32 {
+    // Every step climbs by one, so every trail to a summit is a shortest path
+    let search = walk_trails(grid, starting_pos);
+    search.reached()
+        .filter(|&&pos| grid[pos] == 9)
+        .map(|pos| search.count_paths(pos) as u32)
+        .sum()
+}
+
+
+fn walk_trails(grid: &Grid<u8>, starting_pos: Idx) -> Search<Idx, usize> {
+    bfs(starting_pos, |&pos| {
+        grid.neighbors4(pos)
+            .filter(move |&new_pos| grid[new_pos] == grid[pos] + 1)
+    })
+}
+
+
+/// Rough terrain with hiking trails climbing from 0 to 9 laid over it.
+/// Trails may join where they share a height, but never cross one another
+/// otherwise or double back on themselves.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let size = 8 * scale as usize;
+    let mut trails = Grid::filled(size, size, None);
+
+    for _ in 0..4 * scale * scale {
+        if let Some(trail) = (0..20).find_map(|_| random_trail(rng, &trails)) {
+            for (height, pos) in trail.into_iter().enumerate() {
+                trails[pos] = Some(height as u8);
+            }
+        }
+    }
+
+    let grid = trails.map(|height| height.unwrap_or_else(|| rng.below(10) as u8));
+    grid.render(|_, &height| char::from_digit(height as u32, 10).unwrap())
+}
+
+
+/// A random walk of ten cells that keeps to the heights of any `trails`
+/// already laid, or `None` if it gets stuck.
+fn random_trail(rng: &mut Rng, trails: &Grid<Option<u8>>) -> Option<Vec<Idx>> {
+    let start = (rng.index(trails.nrows()), rng.index(trails.ncols()));
+    if trails[start].is_some_and(|height| height != 0) { return None }
+
+    let mut trail = vec![start];
+    for height in 1..=9 {
+        let next = trails.neighbors4(*trail.last().unwrap())
+            .filter(|pos| !trail.contains(pos))
+            .filter(|&pos| trails[pos].is_none_or(|laid| laid == height))
+            .collect::<Vec<_>>();
+        if next.is_empty() { return None }
+        trail.push(*rng.pick(&next));
+    }
+    Some(trail)
+}
+
+
+fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10).map(|height| height as u8))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day10>();
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..8 {
+            let grid = parse_input(&generate_input(&mut Rng::new(seed), 1)).unwrap();
+            let heads = grid.positions(|&val| val == 0).collect::<Vec<_>>();
+            let score = heads.iter().map(|&pos| trailhead_score(&grid, pos)).sum::<u32>();
+            let rating = heads.iter().map(|&pos| trailhead_rating(&grid, pos)).sum::<u32>();
+            assert!(score >= 3, "seed {seed} scores {score}");
+            assert!(rating >= score, "seed {seed}");
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<u64>;
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("55312"),
+    ];
+
+    const PARAMS: &'static [(&'static str, i64)] = &[
+        ("blinks1", 25),
+        ("blinks2", 75),
+    ];
+
+    const PARAM_RANGES: &'static [(&'static str, RangeInclusive<i64>)] = &[
+        ("blinks1", 0..=i64::MAX),
+        ("blinks2", 0..=i64::MAX),
+    ];
+
+    const REFERENCE_PARAMS: &'static [(&'static str, i64)] = &[
+        ("blinks1", 10),
+        ("blinks2", 25),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, params: &Params) -> Answer {
+        p1(input, params.get("blinks1") as usize)
+    }
+
+    fn part2(input: &Self::Parsed, params: &Params) -> Answer {
+        p2(input, params.get("blinks2") as usize)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+
+    fn reference_part1(input: &Self::Parsed, params: &Params) -> Option<Answer> {
+        Some(naive_blink(input, params.get("blinks1") as usize).into())
+    }
+
+    fn reference_part2(input: &Self::Parsed, params: &Params) -> Option<Answer> {
+        Some(naive_blink(input, params.get("blinks2") as usize).into())
+    }
+}
+
+
+fn p1(stones: &[u64], blinks: usize) -> Answer {
+    let result = blink(stones, blinks);
+    result.into()
+}
+
+
+fn p2(stones: &[u64], blinks: usize) -> Answer {
+    let result = blink(stones, blinks);
+    result.into()
+}
+
+
+
+fn blink_once(stones: HashMap<u64, u64>) -> HashMap<u64, u64> {
+    let mut blinked_stones = HashMap::with_capacity(stones.len());
+    for (stone_val, count) in stones.into_iter() {
+        if stone_val == 0 {
+            *blinked_stones.entry(1).or_default() += count;
+        } else {
+            // Log10 of a number gives number of digits - 1
+            let num_digits = u64::ilog10(stone_val) as u64 + 1;
+            if num_digits.is_multiple_of(2) {
+                let mult = u64::pow(10, (num_digits/2) as u32);
+                let val1 = stone_val / mult;
+                let val2 = stone_val - (val1 * mult);
+                *blinked_stones.entry(val1).or_default() += count;
+                *blinked_stones.entry(val2).or_default() += count;
+            } else {
+                *blinked_stones.entry(stone_val * 2024).or_default() += count;
+            }
+        }
+    }
+
+    blinked_stones
+}
+
+
+
+fn blink(input: &[u64], depth: usize) -> u64 {
+    let mut stones = HashMap::new();
+    for val in input {
+        *stones.entry(*val).or_default() += 1;
+    }
+    for _ in 0..depth {
+        stones = blink_once(stones);
+    }
+    stones.values()
+        .copied()
+        .sum()
+}
+
+
+/// Blinks by rewriting the whole row of stones each time, exactly as the
+/// puzzle describes, and counts what's left.
+fn naive_blink(input: &[u64], blinks: usize) -> usize {
+    let mut stones = input.to_vec();
+    for _ in 0..blinks {
+        stones = stones.into_iter()
+            .flat_map(|stone| {
+                let digits = stone.to_string();
+                if stone == 0 {
+                    vec![1]
+                } else if digits.len().is_multiple_of(2) {
+                    let (left, right) = digits.split_at(digits.len() / 2);
+                    vec![left.parse().unwrap(), right.parse().unwrap()]
+                } else {
+                    vec![stone * 2024]
+                }
+            })
+            .collect();
+    }
+    stones.len()
+}
+
+
+/// A short row of stones, some with single digits and most with longer
+/// numbers.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let stones = (0..2 * scale)
+        .map(|_| if rng.one_in(4) { rng.range(0..10) } else { rng.range(10..1_000_000) })
+        .collect::<Vec<_>>();
+    format!("{}\n", stones.iter().join(" "))
+}
+
+
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    input.split_whitespace()
+        .map(|s| parse::number(input, s))
+        .collect()
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EX: &str = include_str!("example");
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day11>();
+    }
+
+    #[test]
+    fn crosscheck() {
+        crate::differential::assert_agree::<Day11>();
+    }
+
+    #[test]
+    fn test1() {
+        let input = parse_input(EX).unwrap();
+        let result = blink(&input, 6);
+        assert_eq!(result, 22);
+        let result = blink(&input, 25);
+        assert_eq!(result, 55312);
+    }
+
+    #[test]
+    fn test_out_of_range_params() {
+        let input = parse_input(EX).unwrap();
+        let solve = |blinks1, blinks2| {
+            let params = Day11::default_params().with_overrides([("blinks1", blinks1), ("blinks2", blinks2)])?;
+            Ok::<_, String>((Day11::part1(&input, &params).to_string(), Day11::part2(&input, &params).to_string()))
+        };
+        assert_eq!(solve(0, 6), Ok(("2".to_string(), "22".to_string())));
+        assert!(solve(-1, 6).is_err());
+        assert!(solve(25, -75).is_err());
+    }
+
+}
\ No newline at end of file
@@ -1,8 +1,14 @@
-use std::time::Instant;
-use std::collections::VecDeque;
 use rustc_hash::FxHashSet;
 
-use ndarray::Array2;
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::grid::Grid;
+use crate::search::bfs;
 
 
 #[derive(Clone)]
@@ -19,50 +25,50 @@ impl std::fmt::Debug for Region {
 }
 
 
-fn main() {
-    let input = include_str!("input");
+pub struct Day12;
 
-    let start = Instant::now();
-    let _parsed_input = parse_input(input);
-    let parse_duration = start.elapsed().as_secs_f32();
-    println!("Parsing took {parse_duration} secs");
+impl Solution for Day12 {
+    type Parsed = Grid<char>;
 
-    println!();
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("1930").part2("1206"),
+        example!("example2").part1("1184").part2("368"),
+    ];
 
-    let start = Instant::now();
-    let p1_answer = p1(input);
-    let p1_duration = start.elapsed().as_secs_f32();
-    println!("P1: {p1_answer}");
-    println!("Took {p1_duration} secs");
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
 
-    println!();
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
 
-    let start = Instant::now();
-    let p2_answer = p2(input);
-    let p2_duration = start.elapsed().as_secs_f32();
-    println!("P2: {p2_answer}");
-    println!("Took {p2_duration} secs");
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
 }
 
 
-fn p1(input: &str) -> String {
-    let grid = parse_input(input);
-    let regions = parse_regions(&grid);
+fn p1(grid: &Grid<char>) -> Answer {
+    let regions = parse_regions(grid);
     let price = price_regions(&regions);
-    format!("{price}")
+    price.into()
 }
 
 
-fn p2(input: &str) -> String {
-    let grid = parse_input(input);
-    let regions = parse_regions(&grid);
+fn p2(grid: &Grid<char>) -> Answer {
+    let regions = parse_regions(grid);
     let price = bulk_price_regions(&regions);
-    format!("{price}")
+    price.into()
 }
 
 
 fn price_regions(regions: &[Region]) -> u32 {
-    regions.into_iter()
+    regions.iter()
         .map(|region| {
             let area = region.cells.len() as u32;
             area * region.walls
@@ -72,7 +78,7 @@ fn price_regions(regions: &[Region]) -> u32 {
 
 
 fn bulk_price_regions(regions: &[Region]) -> u32 {
-    regions.into_iter()
+    regions.iter()
         .map(|region| {
             let area = region.cells.len() as u32;
             let sides = get_sides(region);
@@ -82,13 +88,13 @@ fn bulk_price_regions(regions: &[Region]) -> u32 {
 }
 
 
-fn parse_regions(grid: &Array2<char>) -> Vec<Region> {
+fn parse_regions(grid: &Grid<char>) -> Vec<Region> {
     let mut visited = FxHashSet::default();
     let mut regions = Vec::new();
 
     for (pos, _) in grid.indexed_iter() {
         if visited.contains(&pos) { continue }
-        let region = bfs(pos, grid);
+        let region = flood_fill(pos, grid);
         regions.push(region.clone());
         visited.extend(region.cells);
     }
@@ -97,23 +103,11 @@ fn parse_regions(grid: &Array2<char>) -> Vec<Region> {
 }
 
 
-fn get_neighbor_info(pos: (usize, usize), grid: &Array2<char>) -> Vec<(usize, usize)> {
-    let (x, y) = pos;
-    let plant = *grid.get(pos).unwrap();
-    let mut neighbors = Vec::new();
-    let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
-
-    for (dx, dy) in directions.iter() {
-        let new_x = x as isize + dx;
-        let new_y = y as isize + dy;
-        if new_x >= 0 && new_y >= 0 && new_x < grid.shape()[0] as isize && new_y < grid.shape()[1] as isize {
-            let new_pos = (new_x as usize, new_y as usize);
-            if *grid.get(new_pos).unwrap() == plant {
-                neighbors.push(new_pos);
-            }
-        }
-    }
-    neighbors
+fn get_neighbor_info(pos: (usize, usize), grid: &Grid<char>) -> Vec<(usize, usize)> {
+    let plant = grid[pos];
+    grid.neighbors4(pos)
+        .filter(|&new_pos| grid[new_pos] == plant)
+        .collect()
 }
 
 
@@ -167,40 +161,51 @@ fn get_sides(region: &Region) -> u32 {
 }
 
 
-fn bfs(pos: (usize, usize), grid: &Array2<char>) -> Region {
-    let mut visited = FxHashSet::default();
-    let mut to_visit = VecDeque::new();
-    let mut walls = 0;
-    to_visit.push_back(pos);
-
-    while let Some(pos) = to_visit.pop_front() {
-        if visited.contains(&pos) { continue }
-        visited.insert(pos);
-        let neighbors = get_neighbor_info(pos, grid);
-        walls += 4 - neighbors.len() as u32;
-        for neighbor in neighbors {
-            to_visit.push_back(neighbor);
-        }
-    }
+fn flood_fill(pos: (usize, usize), grid: &Grid<char>) -> Region {
+    let search = bfs(pos, |&pos| get_neighbor_info(pos, grid));
+    let cells = search.reached().copied().collect::<FxHashSet<_>>();
+    let walls = cells.iter()
+        .map(|&cell| 4 - get_neighbor_info(cell, grid).len() as u32)
+        .sum();
 
     Region {
-        cells: visited,
+        cells,
         walls,
-        plant: *grid.get(pos).unwrap()
+        plant: grid[pos]
     }
 }
 
 
-fn parse_input(input: &str) -> Array2<char> {
-    let nrows = input.lines().count();
-    let ncols = input.lines().next().unwrap().len();
+/// A garden of irregular regions grown outwards from random seeds. Separate
+/// regions may share a plant type, as they do in the real input.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let size = 10 * scale as usize;
+    let mut grid = Grid::filled(size, size, None);
+    let mut frontier = vec![];
+    for _ in 0..11 * scale * scale {
+        let pos = (rng.index(size), rng.index(size));
+        grid[pos] = Some((b'A' + rng.below(26) as u8) as char);
+        frontier.push(pos);
+    }
 
-    let data = input.lines()
-        .map(|line| line.chars())
-        .flatten()
-        .collect::<Vec<char>>();
+    while !frontier.is_empty() {
+        let idx = rng.index(frontier.len());
+        let pos = frontier[idx];
+        let open = grid.neighbors4(pos).filter(|&next| grid[next].is_none()).collect::<Vec<_>>();
+        if open.is_empty() {
+            frontier.swap_remove(idx);
+            continue
+        }
+        let next = *rng.pick(&open);
+        grid[next] = grid[pos];
+        frontier.push(next);
+    }
+    grid.render(|_, plant| plant.unwrap())
+}
 
-    Array2::from_shape_vec((nrows,ncols), data).unwrap()
+
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |c| c.is_ascii_uppercase().then_some(c))
 }
 
 
@@ -209,33 +214,24 @@ mod test {
     use super::*;
 
     const EX: &str = include_str!("example");
-    const EX2: &str = include_str!("example2");
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day12>();
+    }
 
     #[test]
     fn test1() {
-        let grid = parse_input(EX);
-        println!("{:?}", grid);
+        let grid = parse_input(EX).unwrap();
         let region_grid = parse_regions(&grid);
-        println!("{:?}", region_grid);
         assert_eq!(price_regions(&region_grid), 1930);
     }
 
     #[test]
     fn test2() {
-        let grid = parse_input(EX);
-        println!("{:?}", grid);
+        let grid = parse_input(EX).unwrap();
         let region_grid = parse_regions(&grid);
-        println!("{:?}", region_grid);
         assert_eq!(bulk_price_regions(&region_grid), 1206);
     }
 
-    #[test]
-    fn test3() {
-        let grid = parse_input(EX2);
-        let region_grid = parse_regions(&grid);
-        for region in region_grid {
-            println!("{region:?}: {}", get_sides(&region));
-        }
-    }
-
 }
\ No newline at end of file
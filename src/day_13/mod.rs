@@ -0,0 +1,367 @@
+use nom::{
+    character::complete::{digit1, multispace1},
+    combinator::{map, map_res},
+    sequence::tuple,
+    bytes::complete::{tag, is_a},
+    multi::separated_list1,
+    IResult,
+};
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+use crate::geom::Point;
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClawMachine {
+    button_a: Point,
+    button_b: Point,
+    prize: Point,
+}
+
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<ClawMachine>;
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("480"),
+    ];
+
+    const PARAMS: &'static [(&'static str, i64)] = &[
+        ("correction", 10000000000000),
+    ];
+
+    const REFERENCE_PARAMS: &'static [(&'static str, i64)] = &[
+        ("correction", GENERATED_CORRECTION),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, params: &Params) -> Answer {
+        p2(input, params.get("correction"))
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        let generated = Generated::from(generate_input(rng, scale));
+        Some(generated.with_params(&[("correction", GENERATED_CORRECTION)]))
+    }
+
+    fn reference_part1(input: &Self::Parsed, _params: &Params) -> Option<Answer> {
+        Some(input.iter().filter_map(|machine| naive_min_cost(machine, Some(100))).sum::<i64>().into())
+    }
+
+    fn reference_part2(input: &Self::Parsed, params: &Params) -> Option<Answer> {
+        let price = input.iter()
+            .filter_map(|machine| naive_min_cost(&correct_error(machine, params.get("correction")), None))
+            .sum::<i64>();
+        Some(price.into())
+    }
+}
+
+
+fn p1(machines: &[ClawMachine]) -> Answer {
+    let price = machines.iter()
+        .filter_map(|machine| min_cost(machine, Some(100)))
+        .sum::<i64>();
+    price.into()
+}
+
+
+fn p2(machines: &[ClawMachine], correction: i64) -> Answer {
+    let price = machines.iter()
+        .map(|machine| correct_error(machine, correction))
+        .filter_map(|machine| min_cost(&machine, None))
+        .sum::<i64>();
+    price.into()
+}
+
+
+fn correct_error(machine: &ClawMachine, correction: i64) -> ClawMachine {
+    let mut machine = *machine;
+    machine.prize += Point::new(correction, correction);
+
+    machine
+}
+
+
+/// The cheapest way to win the prize, each button pressed at most
+/// `max_presses` times if given.
+fn min_cost(machine: &ClawMachine, max_presses: Option<i64>) -> Option<i64> {
+    // ax * a + bx * b = px
+    // ay * a + by * b = py
+
+    // b = py/by - ay*a/by
+    // ax*a + bx*(py/by - ay*a/by) = px
+    // ax*a + bx*py/by - ay*bx*a/by = px
+    // a*(ax - ay*bx/by) = px - bx*py/by
+    // a*(ax*by - ay*bx) = px*by - bx*py
+
+    let ax = machine.button_a.x;
+    let ay = machine.button_a.y;
+    let bx = machine.button_b.x;
+    let by = machine.button_b.y;
+    let px = machine.prize.x;
+    let py = machine.prize.y;
+
+    let c1 = (px*by).checked_sub(bx*py)?;
+    let c2 = (ax*by).checked_sub(ay*bx)?;
+    let (a, b) = if c2 == 0 {
+        min_cost_collinear(machine, max_presses.unwrap_or(i64::MAX))?
+    } else {
+        let a = c1.checked_div(c2)?;
+        let b = if bx != 0 { (px - ax*a) / bx } else { (py - ay*a).checked_div(by)? };
+        (a, b)
+    };
+
+    let in_limit = max_presses.is_none_or(|max| a <= max && b <= max);
+    if a >= 0 && b >= 0 && in_limit && (a*ax + b*bx == px) && (a*ay + b*by == py) {
+        Some(a * 3 + b)
+    } else {
+        None
+    }
+}
+
+
+/// The cheapest presses `(a, b)` when both buttons move the claw along the
+/// same line, leaving one equation a*u + b*v = w along whichever axis they
+/// move on. Whether they also reach the prize on the other axis is left to
+/// the caller.
+fn min_cost_collinear(machine: &ClawMachine, max_presses: i64) -> Option<(i64, i64)> {
+    let (u, v, w) = if machine.button_a.x != 0 || machine.button_b.x != 0 {
+        (machine.button_a.x, machine.button_b.x, machine.prize.x)
+    } else {
+        (machine.button_a.y, machine.button_b.y, machine.prize.y)
+    };
+    let (u, v, w, max) = (u as i128, v as i128, w as i128, max_presses as i128);
+
+    let (a, b) = match (u, v) {
+        (0, 0) => (0, 0),
+        (0, _) => (0, w / v),
+        (_, 0) => (w / u, 0),
+        _ => {
+            // Every solution is a = a0 + k*v, b = b0 - k*u for whole k, and
+            // the cost changes by 3v - u with each step of k, so the
+            // cheapest lies at one end of the range keeping both in bounds
+            let (g, x, y) = extended_gcd(u, v);
+            if w % g != 0 { return None }
+            let (u, v, w) = (u / g, v / g, w / g);
+            let (a0, b0) = (x * w, y * w);
+
+            let low = div_ceil(-a0, v).max(div_ceil(b0 - max, u));
+            let high = div_floor(b0, u).min(div_floor(max - a0, v));
+            if low > high { return None }
+            let k = if 3 * v > u { low } else { high };
+            (a0 + k * v, b0 - k * u)
+        },
+    };
+    Some((a.try_into().ok()?, b.try_into().ok()?))
+}
+
+
+/// `(g, x, y)` where g is the greatest common divisor of `a` and `b` and
+/// a*x + b*y = g.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+
+fn div_floor(n: i128, d: i128) -> i128 {
+    n.div_euclid(d)
+}
+
+
+fn div_ceil(n: i128, d: i128) -> i128 {
+    -(-n).div_euclid(d)
+}
+
+
+/// The cheapest way to win the prize found by trying every number of A
+/// presses, each button pressed at most `max_presses` times if given.
+fn naive_min_cost(machine: &ClawMachine, max_presses: Option<i64>) -> Option<i64> {
+    let (a, b, prize) = (machine.button_a, machine.button_b, machine.prize);
+    let max_a = [(prize.x, a.x), (prize.y, a.y)].into_iter()
+        .filter(|&(_, step)| step > 0)
+        .map(|(target, step)| target / step)
+        .min()
+        .unwrap_or(0);
+
+    (0..=max_presses.map_or(max_a, |max| max.min(max_a)))
+        .filter_map(|presses_a| {
+            let rest = prize - a * presses_a;
+            let presses_b = match (b.x, b.y) {
+                (0, 0) => 0,
+                (0, step) => rest.y / step,
+                (step, _) => rest.x / step,
+            };
+            let in_limit = max_presses.is_none_or(|max| presses_b <= max);
+            (rest == b * presses_b && presses_b >= 0 && in_limit).then_some(3 * presses_a + presses_b)
+        })
+        .min()
+}
+
+
+/// The correction generated inputs are solved with, small enough that the
+/// naive reference can still search every way of winning part 2.
+const GENERATED_CORRECTION: i64 = 1000;
+
+
+/// Claw machines where about a third of the prizes can be won within 100
+/// presses of each button and another third once corrected. Some have
+/// buttons moving along the same line, or identical buttons.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let machines = (0..4 * scale)
+        .map(|_| {
+            let (a, b) = if rng.one_in(4) {
+                let step = Point::new(rng.range(1..10), rng.range(1..10));
+                let step = if rng.one_in(2) { Point::new(step.x, step.x) } else { step };
+                let a = step * rng.range(1..10);
+                (a, if rng.one_in(4) { a } else { step * rng.range(1..10) })
+            } else {
+                (Point::new(rng.range(10..100), rng.range(10..100)), Point::new(rng.range(10..100), rng.range(10..100)))
+            };
+            let prize = match rng.below(3) {
+                0 => a * rng.range(0..101) + b * rng.range(0..101),
+                1 => {
+                    let target = a * rng.range(100..300) + b * rng.range(100..300);
+                    let offset = GENERATED_CORRECTION;
+                    Point::new((target.x - offset).max(0), (target.y - offset).max(0))
+                },
+                _ => Point::new(rng.range(1000..20000), rng.range(1000..20000)),
+            };
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.x, a.y, b.x, b.y, prize.x, prize.y
+            )
+        })
+        .collect::<Vec<_>>();
+    machines.join("\n")
+}
+
+
+fn parse_input(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    let result = separated_list1(multispace1, parse_machine)
+        (input);
+    parse::finish(input, result)
+}
+
+fn parse_machine(input: &str) -> IResult<&str, ClawMachine> {
+    map(
+        tuple((
+            parse_button,
+            multispace1,
+            parse_button,
+            multispace1,
+            parse_prize
+        )), 
+        |(button_a, _, button_b, _, prize)| {
+            ClawMachine { button_a, button_b, prize }
+        }
+    )(input)
+}
+
+
+fn parse_button(input: &str) -> IResult<&str, Point> {
+    map(
+        tuple((
+            tag("Button "),
+            is_a("AB"),
+            tag(": X+"),
+            parse_value,
+            tag(", Y+"),
+            parse_value,
+        )),
+        |(_, _, _, x, _, y)| Point { x, y }
+    )(input)
+}
+
+
+fn parse_prize(input: &str) -> IResult<&str, Point> {
+    map(
+        tuple((
+            tag("Prize: X="),
+            parse_value,
+            tag(", Y="),
+            parse_value,
+        )),
+        |(_, x, _, y)| Point { x, y }
+    )(input)
+}
+
+
+fn parse_value(input: &str) -> IResult<&str, i64> {
+    map_res(digit1, |s: &str| s.parse())(input)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EX: &str = include_str!("example");
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day13>();
+    }
+
+    #[test]
+    fn crosscheck() {
+        crate::differential::assert_agree::<Day13>();
+    }
+
+    #[test]
+    fn test1() {
+        let machines = parse_input(EX).unwrap();
+        let price = machines.into_iter()
+            .filter_map(|machine| min_cost(&machine, Some(100)))
+            .sum::<i64>();
+        assert_eq!(price, 480)
+    }
+
+    #[test]
+    fn test_collinear() {
+        let machine = |a: (i64, i64), b: (i64, i64), prize: (i64, i64)| ClawMachine {
+            button_a: Point::new(a.0, a.1),
+            button_b: Point::new(b.0, b.1),
+            prize: Point::new(prize.0, prize.1),
+        };
+        assert_eq!(min_cost(&machine((2, 2), (1, 1), (4, 4)), None), Some(4));
+        assert_eq!(min_cost(&machine((1, 0), (1, 0), (1, 0)), None), Some(1));
+        // A's three tokens for 5 beat B's one for 1
+        assert_eq!(min_cost(&machine((5, 10), (1, 2), (12, 24)), None), Some(8));
+        assert_eq!(min_cost(&machine((5, 10), (1, 2), (12, 24)), Some(1)), None);
+        assert_eq!(min_cost(&machine((2, 2), (4, 4), (3, 3)), None), None);
+        assert_eq!(min_cost(&machine((2, 2), (1, 1), (4, 5)), None), None);
+        assert_eq!(min_cost(&machine((3, 0), (2, 0), (1, 0)), None), None);
+    }
+
+    #[test]
+    fn test_press_limit() {
+        let machine = ClawMachine {
+            button_a: Point::new(1, 0),
+            button_b: Point::new(0, 1),
+            prize: Point::new(101, 5),
+        };
+        assert_eq!(min_cost(&machine, Some(100)), None);
+        assert_eq!(min_cost(&machine, None), Some(308));
+    }
+
+}
\ No newline at end of file
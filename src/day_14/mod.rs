@@ -0,0 +1,258 @@
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+use rustc_hash::FxHashSet;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, char, multispace1},
+    combinator::{map, map_res, recognize, opt},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, tuple},
+    IResult
+};
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::cancel;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+use crate::geom::Point;
+use crate::grid::Grid;
+use crate::render::{self, Frame, Rgb, Tile};
+
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pos: Point,
+    vel: Point
+}
+
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Vec<Robot>;
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").params(&[("width", 11), ("height", 7)]).part1("12"),
+    ];
+
+    const PARAMS: &'static [(&'static str, i64)] = &[
+        ("width", 101),
+        ("height", 103),
+    ];
+
+    const PARAM_RANGES: &'static [(&'static str, RangeInclusive<i64>)] = &[
+        ("width", 1..=(i32::MAX as i64)),
+        ("height", 1..=(i32::MAX as i64)),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, params: &Params) -> Answer {
+        p1(input, grid_size(params))
+    }
+
+    fn part2(input: &Self::Parsed, params: &Params) -> Answer {
+        p2(input, grid_size(params))
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale))
+    }
+}
+
+
+fn grid_size(params: &Params) -> Point {
+    Point::new(params.get("width"), params.get("height"))
+}
+
+
+fn p1(robots: &[Robot], grid_size: Point) -> Answer {
+    let mut robots = robots.to_vec();
+    run(grid_size, &mut robots, 100);
+    let sf = safety_factor(grid_size, &robots);
+    sf.into()
+}
+
+
+fn p2(robots: &[Robot], grid_size: Point) -> Answer {
+    match find_tree(grid_size, robots.to_vec()) {
+        Some(ticks) => ticks.into(),
+        None => "no tree".into(),
+    }
+}
+
+
+fn run(grid_size: Point, robots: &mut [Robot], ticks: u32) {
+    for _ in 0..ticks {
+        for robot in robots.iter_mut() {
+            robot.pos = (robot.pos + robot.vel).rem_euclid(grid_size);
+        }
+        render::record(|| robots_frame(grid_size, robots));
+    }
+}
+
+
+fn safety_factor(grid_size: Point, robots: &[Robot]) -> u32 {
+    let mut quad_count = [0; 4];
+
+    for robot in robots {
+        if robot.pos.x < grid_size.x / 2 {
+            if robot.pos.y < grid_size.y / 2 {
+                quad_count[0] += 1;
+            } else if robot.pos.y > grid_size.y / 2 {
+                quad_count[1] += 1;
+            }
+        } else if robot.pos.x > grid_size.x / 2 {
+            if robot.pos.y < grid_size.y / 2 {
+                quad_count[2] += 1;
+            } else if robot.pos.y > grid_size.y / 2 {
+                quad_count[3] += 1;
+            }
+        }
+    }
+
+    quad_count.iter().product()
+}
+
+
+fn has_overlap(robots: &[Robot]) -> bool {
+    let mut seen = FxHashSet::default();
+
+    for robot in robots {
+        if !seen.insert(robot.pos) {
+            return true;
+        }
+    }
+
+    false
+}
+
+
+fn find_tree(grid_size: Point, mut robots: Vec<Robot>) -> Option<u32> {
+    // Once on the grid, every robot is back where it was after width * height ticks
+    let cycle = grid_size.x.saturating_mul(grid_size.y);
+    let mut ticks = 0;
+    render::record(|| robots_frame(grid_size, &robots));
+    while has_overlap(&robots) {
+        cancel::check();
+        if ticks as i64 > cycle {
+            return None;
+        }
+        run(grid_size, &mut robots, 1);
+        ticks += 1;
+    }
+
+    crate::debug!("after {ticks} ticks:\n{}", robots_frame(grid_size, &robots).to_text());
+    Some(ticks)
+}
+
+
+fn robots_frame(grid_size: Point, robots: &[Robot]) -> Frame {
+    let mut grid = Grid::filled(grid_size.y as usize, grid_size.x as usize, 0_u8);
+
+    for robot in robots {
+        if let Some(count) = grid.get_point_mut(robot.pos) {
+            *count += 1;
+        }
+    }
+
+    Frame::from_grid(&grid, |_, &count| match count {
+        0 => Tile::EMPTY,
+        count => Tile::new(char::from_digit(count.min(9) as u32, 10).unwrap(), Rgb::GREEN),
+    })
+}
+
+
+/// Robots on a grid the size of the example at scale 1, placed so they all
+/// stand on separate tiles at some tick within one full cycle, which is what
+/// part 2 waits for.
+fn generate_input(rng: &mut Rng, scale: u32) -> Generated {
+    let size = Point::new(10 * scale as i64 + 1, 6 * scale as i64 + 1);
+    let mut tiles = (0..size.y)
+        .flat_map(|y| (0..size.x).map(move |x| Point::new(x, y)))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut tiles);
+    let apart_at = rng.range(0..size.x * size.y);
+
+    let mut out = String::new();
+    for &pos in tiles.iter().take(12 * (scale * scale) as usize) {
+        let vel = Point::new(rng.range(1 - size.x..size.x), rng.range(1 - size.y..size.y));
+        let start = (pos - vel * apart_at).rem_euclid(size);
+        writeln!(out, "p={},{} v={},{}", start.x, start.y, vel.x, vel.y).unwrap();
+    }
+    Generated::from(out).with_params(&[("width", size.x), ("height", size.y)])
+}
+
+
+fn parse_input(input: &str) -> Result<Vec<Robot>, ParseError> {
+    let result = separated_list1(multispace1, parse_robot)
+    (input);
+    parse::finish(input, result)
+}
+
+
+fn parse_robot(input: &str) -> IResult<&str, Robot> {
+    map(
+        tuple((
+            preceded(tag("p="), parse_pos), 
+            preceded(tag(" v="), parse_pos),
+        )),
+        |(pos, vel)| Robot { pos, vel }
+    )(input)
+}
+
+
+fn parse_pos(input: &str) -> IResult<&str, Point> {
+    map(
+        separated_pair(
+            parse_value,
+            char(','),
+            parse_value
+        ),
+        |(x, y)| Point { x, y }
+    )(input)
+}
+
+
+fn parse_value(input: &str) -> IResult<&str, i64> {
+    map_res(
+        recognize(
+            tuple((
+                opt(char('-')),
+                digit1
+            ))
+        ), 
+        |s: &str| s.parse()
+    )(input)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day14>();
+    }
+
+    #[test]
+    fn test_out_of_range_params() {
+        let robots = parse_input(include_str!("example")).unwrap();
+        let solve = |width, height| {
+            let params = Day14::default_params().with_overrides([("width", width), ("height", height)])?;
+            Ok::<_, String>((Day14::part1(&robots, &params).to_string(), Day14::part2(&robots, &params).to_string()))
+        };
+        assert_eq!(solve(1, 1), Ok(("0".to_string(), "no tree".to_string())));
+        assert!(solve(0, 7).is_err());
+        assert!(solve(11, -7).is_err());
+    }
+
+}
@@ -0,0 +1,296 @@
+use std::fmt::Write;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::geom::{Dir, Point};
+use crate::grid::Grid;
+use crate::render::{self, Frame, Rgb, Tile};
+
+
+#[derive(Clone)]
+pub struct Map {
+    data: Grid<Object>,
+    robot_pos: Point,
+}
+
+
+impl Map {
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.data, |_, obj| match obj {
+            Object::Wall => Tile::WALL,
+            Object::Box => Tile::new('O', Rgb::YELLOW),
+            Object::Empty => Tile::EMPTY,
+            Object::WideBoxLeft => Tile::new('[', Rgb::YELLOW),
+            Object::WideBoxRight => Tile::new(']', Rgb::YELLOW),
+        })
+        .overlay([self.robot_pos], Tile::new('@', Rgb::RED))
+    }
+}
+
+
+impl std::fmt::Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.frame().to_text())?;
+        f.write_char('\n')?;
+        Ok(())
+    }
+}
+
+
+#[derive(Clone, Copy, Debug)]
+enum Object {
+    Wall,
+    Box,
+    Empty,
+    WideBoxLeft,
+    WideBoxRight,
+}
+
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = (Map, Vec<Dir>);
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("10092").part2("9021"),
+        example!("example2").part1("2028"),
+        example!("example3").part2("618"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+}
+
+
+fn p1((map, dirs): &(Map, Vec<Dir>)) -> Answer {
+    let mut map = map.clone();
+
+    render::record(|| map.frame());
+    for dir in dirs.iter() {
+        step(&mut map, *dir);
+        render::record(|| map.frame());
+    }
+
+    get_box_sum(&map.data).into()
+}
+
+
+fn p2((map, dirs): &(Map, Vec<Dir>)) -> Answer {
+    let mut map = widen(map);
+
+    render::record(|| map.frame());
+    for dir in dirs.iter() {
+        step(&mut map, *dir);
+        render::record(|| map.frame());
+    }
+
+    get_box_sum(&map.data).into()
+}
+
+
+fn step(map: &mut Map, dir: Dir) {
+    let cur_pos = map.robot_pos;
+
+    if let Some((new_pos, new_grid)) = try_move(map.data.clone(), cur_pos, dir) {
+        map.data = new_grid;
+        map.robot_pos = new_pos;
+    }
+}
+
+
+fn try_move(mut grid: Grid<Object>, pos: Point, dir: Dir) -> Option<(Point, Grid<Object>)> {
+    let this_obj = *grid.get_point(pos)?;
+
+    if matches!(this_obj, Object::Wall) {
+        panic!("Attempted to move {this_obj:?}");
+    }
+
+    let new_pos = pos + dir.delta();
+    let new_pos_obj = *grid.get_point(new_pos)?;
+
+    match new_pos_obj {
+        Object::Wall => return None,
+        Object::Box => {
+            let (_, new_grid) = try_move(grid, new_pos, dir)?;
+            grid = new_grid;
+        },
+        Object::Empty => (),
+        Object::WideBoxLeft => {
+            let (_, new_grid) = try_move(grid, new_pos, dir)?;
+            if dir != Dir::East {
+                let new_pos_box = new_pos + Dir::East.delta();
+                let (_, new_grid) = try_move(new_grid, new_pos_box, dir)?;
+                grid = new_grid;
+            } else {
+                grid = new_grid;
+            }
+        },
+        Object::WideBoxRight => {
+            let (_, new_grid) = try_move(grid, new_pos, dir)?;
+            if dir != Dir::West {
+                let new_pos_box = new_pos + Dir::West.delta();
+                let (_, new_grid) = try_move(new_grid, new_pos_box, dir)?;
+                grid = new_grid;
+            } else {
+                grid = new_grid;
+            }
+        }
+    }
+
+    *grid.get_point_mut(new_pos)? = this_obj;
+    *grid.get_point_mut(pos)? = Object::Empty;
+
+    Some((new_pos, grid))
+}
+
+
+fn get_box_sum(grid: &Grid<Object>) -> u64 {
+    grid.indexed_iter()
+        .filter(|(_, &obj)| matches!(obj, Object::Box) || matches!(obj, Object::WideBoxLeft))
+        .map(|((row, col), _)| row as u64 * 100 + col as u64)
+        .sum() 
+}
+
+
+fn widen(map: &Map) -> Map {
+    let (nrows, ncols) = map.data.dim();
+
+    let map_data = map.data.iter()
+        .flat_map(|obj| match obj {
+            Object::Box => [Object::WideBoxLeft, Object::WideBoxRight],
+            &obj => [obj, obj],
+        })
+        .collect::<Vec<Object>>();
+
+    let map_data = Grid::from_vec(nrows, ncols*2, map_data);
+    let robot_pos = Point::new(map.robot_pos.x*2, map.robot_pos.y);
+
+    Map { data: map_data, robot_pos }
+}
+
+
+/// A walled warehouse with scattered walls and boxes, the robot on any tile
+/// inside, and rows of random moves.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let size = 10 * scale as usize;
+    let cells = (0..size * size)
+        .map(|_| match rng.below(10) {
+            0 => '#',
+            1..=3 => 'O',
+            _ => '.',
+        })
+        .collect();
+    let mut grid = Grid::from_vec(size, size, cells);
+    for idx in 0..size {
+        grid[(0, idx)] = '#';
+        grid[(size - 1, idx)] = '#';
+        grid[(idx, 0)] = '#';
+        grid[(idx, size - 1)] = '#';
+    }
+    grid[(rng.index(size - 2) + 1, rng.index(size - 2) + 1)] = '@';
+
+    let moves = (0..700 * scale * scale)
+        .map(|_| *rng.pick(&['^', '>', 'v', '<']))
+        .collect::<Vec<_>>();
+    let moves = moves.chunks(70)
+        .map(|line| line.iter().collect::<String>())
+        .collect::<Vec<_>>();
+    format!("{grid}\n{}\n", moves.join("\n"))
+}
+
+
+fn parse_input(input: &str) -> Result<(Map, Vec<Dir>), ParseError> {
+    let (map_section, dir_section) = input.split_once("\n\n")
+        .ok_or_else(|| ParseError::at_end(input, "expected a blank line between the map and the moves"))?;
+
+    let chars = Grid::parse(map_section, |c| matches!(c, '#' | '.' | 'O' | '@').then_some(c))?;
+    let robot_pos = Point::from_idx(chars.find_unique(map_section, &'@', "robot (`@`)")?);
+
+    let map_data = chars.map(|&c| match c {
+        '#' => Object::Wall,
+        'O' => Object::Box,
+        _ => Object::Empty,
+    });
+
+    let dirs = dir_section.lines()
+        .flat_map(|line| line.char_indices().map(move |(idx, c)| (&line[idx..], c)))
+        .map(|(rest, c)| {
+            Dir::from_arrow(c)
+                .ok_or_else(|| ParseError::at(input, rest, format!("expected a move (`^`, `>`, `v` or `<`), found {c:?}")))
+        })
+        .collect::<Result<Vec<Dir>, _>>()?;
+    
+    Ok((
+        Map { data: map_data, robot_pos },
+        dirs
+    ))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EX: &str = include_str!("example");
+    const EX2: &str = include_str!("example2");
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day15>();
+    }
+
+    #[test]
+    fn test1() {
+        let (mut map, dirs) = parse_input(EX).unwrap();
+        for dir in dirs {
+            step(&mut map, dir);
+        }
+
+        assert_eq!(get_box_sum(&map.data), 10092);
+    }
+
+    #[test]
+    fn test2() {
+        let (mut map, dirs) = parse_input(EX2).unwrap();
+        for dir in dirs {
+            step(&mut map, dir);
+        }
+        assert_eq!(
+            map.to_string().trim_end(),
+            "########\n#....OO#\n##.....#\n#.....O#\n#.#O@..#\n#...O..#\n#...O..#\n########"
+        );
+
+        assert_eq!(get_box_sum(&map.data), 2028);
+    }
+
+    #[test]
+    fn test3() {
+        let (map, dirs) = parse_input(EX).unwrap();
+        let mut map = widen(&map);
+        for &dir in dirs.iter() {
+            step(&mut map, dir);
+        }
+
+        assert_eq!(get_box_sum(&map.data), 9021);
+    }
+
+}
\ No newline at end of file
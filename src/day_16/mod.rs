@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{self, Generated, Rng};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::geom::{Dir, Point};
+use crate::grid::Grid;
+use crate::render::{self, Frame, Rgb, Tile};
+use crate::search::dijkstra;
+
+#[derive(Debug)]
+pub enum MapObj {
+    Empty,
+    Wall,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pos {
+    point: Point,
+    dir: Dir,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = (Grid<MapObj>, Pos, Pos);
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("7036").part2("45"),
+        example!("example2").part1("11048").part2("64"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+}
+
+
+fn p1((map, start, end): &(Grid<MapObj>, Pos, Pos)) -> Answer {
+    let (cost, _) = shortest_path_cost(map, *start, *end);
+    cost.into()
+}
+
+
+fn p2((map, start, end): &(Grid<MapObj>, Pos, Pos)) -> Answer {
+    let (_, best_paths) = shortest_path_cost(map, *start, *end);
+    render::record(|| {
+        map_frame(map)
+            .overlay(best_paths.iter().copied(), Tile::new('O', Rgb::GREEN))
+            .overlay([start.point], Tile::new('S', Rgb::RED))
+            .overlay([end.point], Tile::new('E', Rgb::RED))
+    });
+    best_paths.len().into()
+}
+
+
+fn map_frame(map: &Grid<MapObj>) -> Frame {
+    Frame::from_grid(map, |_, obj| match obj {
+        MapObj::Wall => Tile::WALL,
+        MapObj::Empty => Tile::EMPTY,
+    })
+}
+
+
+fn shortest_path_cost(map: &Grid<MapObj>, start: Pos, end: Pos) -> (usize, HashSet<Point>) {
+    let search = dijkstra(start, |&pos| {
+        let Pos { point, dir } = pos;
+        let turns = [
+            (Pos { point, dir: dir.turn_left() }, 1000),
+            (Pos { point, dir: dir.turn_right() }, 1000),
+        ];
+        get_move(map, pos).map(|new_pos| (new_pos, 1))
+            .into_iter()
+            .chain(turns)
+    });
+
+    let ends = Dir::ALL.map(|dir| Pos { point: end.point, dir });
+    let shortest_cost = ends.iter()
+        .filter_map(|pos| search.dist(pos))
+        .min()
+        .unwrap_or(usize::MAX);
+    let best_ends = ends.into_iter()
+        .filter(|pos| search.dist(pos) == Some(shortest_cost));
+    let best_paths = search.nodes_on_paths(best_ends)
+        .into_iter()
+        .map(|pos| pos.point)
+        .collect();
+
+    (shortest_cost, best_paths)
+}
+
+
+fn get_move(map: &Grid<MapObj>, pos: Pos) -> Option<Pos> {
+    let new_point = pos.point + pos.dir.delta();
+    let map_obj = map.get_point(new_point)?;
+
+    if matches!(map_obj, MapObj::Empty) {
+        Some(Pos { point: new_point, dir: pos.dir })
+    } else {
+        None
+    }
+}
+
+
+/// A maze the size of the example at scale 1, with some walls knocked
+/// through so there are several routes and sometimes ties for the best.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let rooms = 7 * scale as usize;
+    let mut walls = generate::maze(rng, rooms, rooms);
+    let size = 2 * rooms + 1;
+    for row in 1..size - 1 {
+        for col in 1..size - 1 {
+            // Walls between two rooms have exactly one odd coordinate
+            if (row + col) % 2 == 1 && rng.one_in(8) {
+                walls[(row, col)] = false;
+            }
+        }
+    }
+
+    let (start, end) = ((size - 2, 1), (1, size - 2));
+    walls.render(|pos, &wall| match wall {
+        _ if pos == start => 'S',
+        _ if pos == end => 'E',
+        true => '#',
+        false => '.',
+    })
+}
+
+
+fn parse_input(input: &str) -> Result<(Grid<MapObj>, Pos, Pos), ParseError> {
+    let chars = Grid::parse(input, |c| matches!(c, '.' | '#' | 'S' | 'E').then_some(c))?;
+    let start = chars.find_unique(input, &'S', "start (`S`)")?;
+    let end = chars.find_unique(input, &'E', "end (`E`)")?;
+
+    let map = chars.map(|&c| match c {
+        '#' => MapObj::Wall,
+        _ => MapObj::Empty,
+    });
+    let start = Pos {
+        point: Point::from_idx(start),
+        dir: Dir::East,
+    };
+    let end = Pos {
+        point: Point::from_idx(end),
+        dir: Dir::North,
+    };
+    Ok((map, start, end))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EX: &str = include_str!("example");
+    const EX2: &str = include_str!("example2");
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day16>();
+    }
+
+    #[test]
+    fn test1() {
+        let (map, start, end) = parse_input(EX).unwrap();
+        let (cost, best_paths) = shortest_path_cost(&map, start, end);
+
+        assert_eq!(cost, 7036);
+        assert_eq!(best_paths.len(), 45);
+    }
+
+    #[test]
+    fn test2() {
+        let (map, start, end) = parse_input(EX2).unwrap();
+        let (cost, best_paths) = shortest_path_cost(&map, start, end);
+
+        assert_eq!(cost, 11048);
+        assert_eq!(best_paths.len(), 64);
+    }
+
+}
\ No newline at end of file
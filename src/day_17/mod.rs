@@ -0,0 +1,340 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use itertools::Itertools;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::cancel;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+
+static REGISTER_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"Register \w: (?P<val>\d+)").unwrap()
+});
+
+#[derive(Clone, Debug)]
+pub struct Program {
+    reg_a: i64,
+    reg_b: i64,
+    reg_c: i64,
+    program: Vec<i64>,
+}
+
+impl Program {
+    fn new(reg_a: i64, reg_b: i64, reg_c: i64, program: Vec<i64>) -> Self {
+        Self {
+            reg_a,
+            reg_b,
+            reg_c,
+            program,
+        }
+    }
+
+    /// Runs the program to completion with register A set to `reg_a`,
+    /// returning everything it outputs.
+    fn run(&self, reg_a: i64) -> Vec<i64> {
+        let (mut a, mut b, mut c) = (reg_a, self.reg_b, self.reg_c);
+        let mut output = vec![];
+        let mut ip = 0;
+
+        while ip + 1 < self.program.len() {
+            cancel::check();
+            let Ok(opcode) = Opcode::try_from(self.program[ip]) else { break };
+            let operand = self.program[ip + 1];
+            // Only reachable by jumping to an odd address, past what the
+            // parser checks, so the program halts there
+            if opcode.takes_combo() && !(0..=6).contains(&operand) { break }
+            let combo = || match operand {
+                4 => a,
+                5 => b,
+                6 => c,
+                literal => literal,
+            };
+
+            match opcode {
+                Opcode::Adv => a = shift(a, combo()),
+                Opcode::Bxl => b ^= operand,
+                Opcode::Bst => b = combo() % 8,
+                Opcode::Jnz => if a != 0 {
+                    ip = operand as usize;
+                    continue
+                },
+                Opcode::Bxc => b ^= c,
+                Opcode::Out => output.push(combo() % 8),
+                Opcode::Bdv => b = shift(a, combo()),
+                Opcode::Cdv => c = shift(a, combo()),
+            }
+            ip += 2;
+        }
+
+        output
+    }
+}
+
+enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    /// Whether the operand is a combo operand rather than a literal.
+    fn takes_combo(&self) -> bool {
+        !matches!(self, Opcode::Bxl | Opcode::Jnz | Opcode::Bxc)
+    }
+}
+
+impl TryFrom<i64> for Opcode {
+    type Error = i64;
+
+    fn try_from(val: i64) -> Result<Self, i64> {
+        match val {
+            0 => Ok(Opcode::Adv),
+            1 => Ok(Opcode::Bxl),
+            2 => Ok(Opcode::Bst),
+            3 => Ok(Opcode::Jnz),
+            4 => Ok(Opcode::Bxc),
+            5 => Ok(Opcode::Out),
+            6 => Ok(Opcode::Bdv),
+            7 => Ok(Opcode::Cdv),
+            _ => Err(val),
+        }
+    }
+}
+
+
+/// `value` divided by two to the power of `bits`, which leaves nothing once
+/// every bit is shifted out.
+fn shift(value: i64, bits: i64) -> i64 {
+    u32::try_from(bits).ok()
+        .and_then(|bits| value.checked_shr(bits))
+        .unwrap_or(0)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = Program;
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("4,6,3,5,6,3,5,2,1,0"),
+        example!("example2").part2("117440"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+
+    fn reference_part1(input: &Self::Parsed, _params: &Params) -> Option<Answer> {
+        Some(interpret(input).into_iter().collect())
+    }
+}
+
+
+fn p1(program: &Program) -> Answer {
+    program.run(program.reg_a).into_iter().collect()
+}
+
+
+fn p2(program: &Program) -> Answer {
+    let results = find_solutions(program, program.program.len());
+
+    match results.into_iter().min() {
+        Some(reg_a) => reg_a.into(),
+        None => "no solution".into(),
+    }
+}
+
+
+/// Every value of register A for which the program outputs the last
+/// `len` values of itself. Relies on the program consuming A three bits
+/// per output, so each extra output fixes three more low bits of A.
+fn find_solutions(program: &Program, len: usize) -> Vec<i64> {
+    if len == 0 {
+        return vec![0]
+    }
+
+    let truth = &program.program[program.program.len() - len..];
+    find_solutions(program, len - 1).into_iter()
+        .flat_map(|a_high| (0..8).map(move |a_low| a_high * 8 + a_low))
+        .filter(|&a| program.run(a) == truth)
+        .collect()
+}
+
+
+/// Runs the program one instruction at a time, spelled out as in the puzzle
+/// text, returning everything it outputs. Kept apart from [`Program::run`]
+/// so the two can be checked against each other.
+fn interpret(program: &Program) -> Vec<i64> {
+    let (mut a, mut b, mut c) = (program.reg_a, program.reg_b, program.reg_c);
+    let mut ip = 0;
+    let mut output = vec![];
+
+    while let (Some(&opcode), Some(&operand)) = (program.program.get(ip), program.program.get(ip + 1)) {
+        cancel::check();
+        let combo = match operand {
+            4 => a,
+            5 => b,
+            6 => c,
+            literal => literal,
+        };
+        // A numerator divided by two to the power of the combo operand
+        let divide = |numerator: i64| {
+            u32::try_from(combo).ok()
+                .and_then(|power| 2_i64.checked_pow(power))
+                .map_or(0, |denominator| numerator / denominator)
+        };
+
+        ip += 2;
+        match opcode {
+            0 => a = divide(a),
+            1 => b ^= operand,
+            2 => b = combo % 8,
+            3 => if a != 0 { ip = operand as usize },
+            4 => b ^= c,
+            5 => output.push(combo % 8),
+            6 => b = divide(a),
+            7 => c = divide(a),
+            _ => panic!("Invalid opcode {opcode}"),
+        }
+    }
+
+    output
+}
+
+
+/// A program shaped like every real input: one loop that mixes the low
+/// three bits of A with higher ones, prints them and shifts A right by
+/// three. Retried until part 2 has an answer. The program always prints
+/// itself, so `scale` has no effect.
+fn generate_input(rng: &mut Rng, _scale: u32) -> String {
+    loop {
+        let mut mix = [[1, rng.range(0..8)], [4, rng.range(0..8)]];
+        rng.shuffle(&mut mix);
+        let mut tail = [[0, 3], [5, 5]];
+        rng.shuffle(&mut tail);
+        let instructions = [[2, 4], [1, rng.range(0..8)], [7, 5]].into_iter()
+            .chain(mix)
+            .chain(tail)
+            .chain([[3, 0]])
+            .flatten()
+            .collect();
+
+        let program = Program::new(rng.range(1 << 40..1 << 47), 0, 0, instructions);
+        if !find_solutions(&program, program.program.len()).is_empty() {
+            return format!(
+                "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                program.reg_a,
+                program.program.iter().join(",")
+            )
+        }
+    }
+}
+
+
+fn parse_input(input: &str) -> Result<Program, ParseError> {
+    let mut line_iter = input.lines();
+
+    let mut next_register = || {
+        let line = line_iter.next()
+            .ok_or_else(|| ParseError::at_end(input, "expected a `Register X: N` line"))?;
+        let captures = REGISTER_RE.captures(line)
+            .ok_or_else(|| ParseError::at(input, line, "expected a `Register X: N` line"))?;
+        parse::number(input, captures.name("val").unwrap().as_str())
+    };
+    let reg_a = next_register()?;
+    let reg_b = next_register()?;
+    let reg_c = next_register()?;
+
+    let line = line_iter.find(|line| !line.is_empty())
+        .ok_or_else(|| ParseError::at_end(input, "expected a `Program: ...` line"))?;
+    let items = line.strip_prefix("Program: ")
+        .ok_or_else(|| ParseError::at(input, line, "expected a `Program: ...` line"))?
+        .split(",")
+        .collect::<Vec<_>>();
+    let program = items.iter()
+        .map(|s| {
+            let value = parse::number(input, s)?;
+            if !(0..=7).contains(&value) {
+                return Err(ParseError::at(input, s, format!("expected a 3-bit number, found {value}")))
+            }
+            Ok(value)
+        })
+        .collect::<Result<Vec<i64>, _>>()?;
+
+    for (idx, pair) in program.chunks_exact(2).enumerate() {
+        if Opcode::try_from(pair[0]).is_ok_and(|opcode| opcode.takes_combo()) && pair[1] == 7 {
+            return Err(ParseError::at(input, items[2 * idx + 1], "combo operand 7 is reserved"))
+        }
+    }
+
+    Ok(Program::new(reg_a, reg_b, reg_c, program))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day17>();
+    }
+
+    #[test]
+    fn crosscheck() {
+        crate::differential::assert_agree::<Day17>();
+    }
+
+    #[test]
+    fn test_invalid_program() {
+        let program = |items| format!("Register A: 10\nRegister B: 0\nRegister C: 0\n\nProgram: {items}\n");
+
+        let err = parse_input(&program("9,1")).unwrap_err();
+        assert_eq!((err.line, err.col), (5, 10));
+        assert!(err.message.contains("found 9"), "{err}");
+
+        let err = parse_input(&program("1,7,5,7")).unwrap_err();
+        assert_eq!((err.line, err.col), (5, 16));
+        assert!(err.message.contains("operand 7"), "{err}");
+
+        let err = parse_input(&program("0,x")).unwrap_err();
+        assert_eq!((err.line, err.col), (5, 12));
+    }
+
+    #[test]
+    fn test_no_solution() {
+        // The first example never outputs a copy of its own program
+        let program = parse_input(include_str!("example")).unwrap();
+        assert_eq!(p2(&program).to_string(), "no solution");
+    }
+
+    #[test]
+    fn test_large_shift() {
+        // A shifted by its own value, far more than 64 bits
+        let program = parse_input("Register A: 100\nRegister B: 0\nRegister C: 0\n\nProgram: 0,4,5,4\n").unwrap();
+        assert_eq!(program.run(program.reg_a), [0]);
+    }
+
+}
@@ -0,0 +1,177 @@
+use std::ops::RangeInclusive;
+
+use rustc_hash::FxHashSet;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+use crate::geom::Point;
+use crate::render::{self, Frame, Rgb, Tile};
+use crate::search::astar;
+
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = Vec<Point>;
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").params(&[("size", 7), ("bytes", 12)]).part1("22").part2("6,1"),
+    ];
+
+    const PARAMS: &'static [(&'static str, i64)] = &[
+        ("size", 71),
+        ("bytes", 1024),
+    ];
+
+    const PARAM_RANGES: &'static [(&'static str, RangeInclusive<i64>)] = &[
+        ("size", 1..=i64::MAX),
+        ("bytes", 0..=i64::MAX),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, params: &Params) -> Answer {
+        p1(input, params.get("size"), fallen(input, params))
+    }
+
+    fn part2(input: &Self::Parsed, params: &Params) -> Answer {
+        p2(input, params.get("size"), fallen(input, params))
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale))
+    }
+}
+
+
+/// The `bytes` parameter, limited to the bytes actually listed.
+fn fallen(bytes: &[Point], params: &Params) -> usize {
+    (params.get("bytes") as usize).min(bytes.len())
+}
+
+
+fn find_shortest_path(map_size: i64, bytes: &[Point]) -> Option<i64> {
+    let blocked = FxHashSet::from_iter(bytes.iter().cloned());
+    let goal = Point::new(map_size-1, map_size-1);
+
+    astar(
+        Point::ZERO,
+        |&pos| get_neighbors(map_size, &blocked, pos).into_iter().map(|pos| (pos, 1)),
+        |&pos| heuristic(pos, goal),
+        |&pos| pos == goal,
+    ).map(|(_, dist)| dist)
+}
+
+
+fn get_neighbors(map_size: i64, blocked: &FxHashSet<Point>, cur_pos: Point) -> Vec<Point> {
+    cur_pos.neighbors4()
+        .filter(|new_pos| (0..map_size).contains(&new_pos.x) && (0..map_size).contains(&new_pos.y))
+        .filter(|new_pos| !blocked.contains(new_pos))
+        .collect()
+}
+
+
+fn heuristic(cur_pos: Point, goal: Point) -> i64 {
+    cur_pos.manhattan(goal)
+}
+
+
+fn p1(bytes: &[Point], map_size: i64, fallen: usize) -> Answer {
+    render::record(|| bytes_frame(map_size, &bytes[0..fallen]));
+    crate::trace!("map after {fallen} bytes:\n{}", bytes_frame(map_size, &bytes[0..fallen]).to_text());
+
+    match find_shortest_path(map_size, &bytes[0..fallen]) {
+        Some(dist) => dist.into(),
+        None => "no path".into(),
+    }
+}
+
+
+fn p2(bytes: &[Point], map_size: i64, fallen: usize) -> Answer {
+    let mut byte: Option<_> = None;
+
+    for idx in fallen..bytes.len() {
+        render::record(|| bytes_frame(map_size, &bytes[0..=idx]));
+        if find_shortest_path(map_size, &bytes[0..=idx]).is_none() { byte = Some(bytes[idx]); break }
+    }
+
+    match byte {
+        Some(byte) => byte.into(),
+        None => "never blocked".into(),
+    }
+}
+
+
+fn bytes_frame(map_size: i64, bytes: &[Point]) -> Frame {
+    let size = map_size as usize;
+    Frame::new(size, size, Tile::EMPTY)
+        .overlay(bytes.iter().copied(), Tile::new('#', Rgb::RED))
+}
+
+
+/// Every tile but the two corners in random order, listed until well past
+/// the byte that first cuts the corners apart. Solved with a `size` that
+/// grows with `scale` and `bytes` about half way to that cut.
+fn generate_input(rng: &mut Rng, scale: u32) -> Generated {
+    let size = 7 * scale as i64;
+    let goal = Point::new(size - 1, size - 1);
+    let mut bytes = (0..size)
+        .flat_map(|y| (0..size).map(move |x| Point::new(x, y)))
+        .filter(|&pos| pos != Point::ZERO && pos != goal)
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut bytes);
+
+    let cut = (0..bytes.len()).collect::<Vec<_>>()
+        .partition_point(|&idx| find_shortest_path(size, &bytes[..=idx]).is_some());
+    bytes.truncate(cut + 1 + (bytes.len() - cut - 1) / 2);
+
+    let out = bytes.iter()
+        .map(|pos| format!("{},{}\n", pos.x, pos.y))
+        .collect::<String>();
+    Generated::from(out).with_params(&[("size", size), ("bytes", cut as i64 / 2)])
+}
+
+
+fn parse_input(input: &str) -> Result<Vec<Point>, ParseError> {
+    input.lines()
+        .map(|line| {
+            let (x_str, y_str) = line.split_once(",")
+                .ok_or_else(|| ParseError::at(input, line, "expected a position like `5,4`"))?;
+            Ok(Point::new(parse::number(input, x_str)?, parse::number(input, y_str)?))
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day18>();
+    }
+
+    #[test]
+    fn test_out_of_range_params() {
+        let bytes = parse_input("1,0\n1,1\n").unwrap();
+        let solve = |size, fallen| {
+            let params = Day18::default_params().with_overrides([("size", size), ("bytes", fallen)])?;
+            Ok::<_, String>((Day18::part1(&bytes, &params).to_string(), Day18::part2(&bytes, &params).to_string()))
+        };
+        assert_eq!(solve(3, 100), Ok(("4".to_string(), "never blocked".to_string())));
+        assert_eq!(solve(2, 2), Ok(("no path".to_string(), "never blocked".to_string())));
+        assert_eq!(solve(2, 0), Ok(("2".to_string(), "1,1".to_string())));
+        assert_eq!(solve(1, 0), Ok(("0".to_string(), "never blocked".to_string())));
+        assert!(solve(3, -5).is_err());
+        assert!(solve(0, 1).is_err());
+    }
+
+}
@@ -0,0 +1,291 @@
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::ParseError;
+
+
+/// The color of one stripe, in the order the trie indexes them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    White,
+    Blue,
+    Black,
+    Red,
+    Green,
+}
+
+impl Color {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'w' => Some(Color::White),
+            'u' => Some(Color::Blue),
+            'b' => Some(Color::Black),
+            'r' => Some(Color::Red),
+            'g' => Some(Color::Green),
+            _ => None,
+        }
+    }
+}
+
+
+type Pattern = Vec<Color>;
+
+
+#[derive(Debug)]
+struct TrieNode {
+    children: [usize; 5],
+    complete: bool
+}
+
+impl TrieNode {
+    fn new() -> Self {
+        Self { children: [0; 5], complete: false }
+    }
+}
+
+
+#[derive(Debug)]
+struct Trie {
+    nodes: Vec<TrieNode>
+}
+
+
+impl Trie {
+    fn new() -> Self {
+        let mut nodes = Vec::with_capacity(1000);
+        nodes.push(TrieNode::new());
+        Self {
+            nodes
+        }
+    }
+
+    fn insert(&mut self, pattern: &[Color]) {
+        let mut cur_node_idx = 0;
+        for &color in pattern {
+            let color_idx = color as usize;
+            if self.nodes[cur_node_idx].children[color_idx] == 0 {
+                self.nodes[cur_node_idx].children[color_idx] = self.nodes.len();
+                self.nodes.push(TrieNode::new());
+            }
+            cur_node_idx = self.nodes[cur_node_idx].children[color_idx];
+        }
+        self.nodes[cur_node_idx].complete = true;
+    }
+}
+
+
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = (Vec<Pattern>, Vec<Pattern>);
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("6").part2("16"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+
+    fn reference_part1(input: &Self::Parsed, _params: &Params) -> Option<Answer> {
+        let (available, designs) = input;
+        Some(designs.iter().filter(|design| count_arrangements(available, design) > 0).count().into())
+    }
+
+    fn reference_part2(input: &Self::Parsed, _params: &Params) -> Option<Answer> {
+        let (available, designs) = input;
+        Some(designs.iter().map(|design| count_arrangements(available, design)).sum::<usize>().into())
+    }
+}
+
+
+fn p1((available, designs): &(Vec<Pattern>, Vec<Pattern>)) -> Answer {
+    let trie = generate_trie(available);
+
+    let count = designs.iter()
+        .filter(|design| find_possible_combos(&trie, design) > 0)
+        .count();
+
+    count.into()
+}
+
+
+fn p2((available, designs): &(Vec<Pattern>, Vec<Pattern>)) -> Answer {
+    let trie = generate_trie(available);
+
+    let sum = designs.iter()
+        .map(|design| find_possible_combos(&trie, design))
+        .sum::<usize>();
+
+    sum.into()
+}
+
+
+fn find_possible_combos(trie: &Trie, design: &[Color]) -> usize {
+    let mut possibilities = vec![0; design.len()+1];
+    possibilities[0] = 1;
+
+    for start_idx in 0..design.len() {
+        if possibilities[start_idx] > 0 {
+            let mut cur_trie_idx = 0;
+
+            for end_idx in start_idx..design.len() {
+                let color_idx = design[end_idx] as usize;
+                cur_trie_idx = trie.nodes[cur_trie_idx].children[color_idx];
+
+                if cur_trie_idx == 0 {
+                    break
+                }
+
+                if trie.nodes[cur_trie_idx].complete {
+                    possibilities[end_idx+1] += possibilities[start_idx];
+                }
+            }
+        }
+    }
+
+    possibilities[design.len()]
+}
+
+
+fn generate_trie(patterns: &[Pattern]) -> Trie {
+    let mut trie = Trie::new();
+
+    for pattern in patterns {
+        trie.insert(pattern);
+    }
+
+    trie
+}
+
+
+
+/// Counts the ways to make `design` by trying every pattern at its start
+/// and recursing on the rest, remembering nothing. Empty patterns are
+/// skipped, as they would never make progress.
+fn count_arrangements(patterns: &[Pattern], design: &[Color]) -> usize {
+    if design.is_empty() {
+        return 1
+    }
+    patterns.iter()
+        .filter(|pattern| !pattern.is_empty())
+        .filter_map(|pattern| design.strip_prefix(pattern.as_slice()))
+        .map(|rest| count_arrangements(patterns, rest))
+        .sum()
+}
+
+
+/// Towel patterns of a few stripes, never including one of the single
+/// stripe towels, and designs mostly built from the patterns, so some but
+/// not all of them can be made.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+    let missing = rng.pick(&COLORS).to_string();
+    let max_len = (3 + scale as i64 / 2).min(8);
+
+    let mut patterns = vec![];
+    while patterns.len() < 8 * scale as usize {
+        let pattern = (0..rng.range(1..max_len + 1))
+            .map(|_| *rng.pick(&COLORS))
+            .collect::<String>();
+        if pattern != missing && !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+
+    let designs = (0..8 * scale)
+        .map(|_| {
+            let len = rng.range(4..4 + 3 * scale as i64) as usize;
+            let mut design = String::new();
+            while design.len() < len {
+                if rng.one_in(8) {
+                    design.push(*rng.pick(&COLORS));
+                } else {
+                    design.push_str(&patterns[rng.index(patterns.len())]);
+                }
+            }
+            design
+        })
+        .collect::<Vec<_>>();
+
+    format!("{}\n\n{}\n", patterns.join(", "), designs.join("\n"))
+}
+
+
+fn parse_input(input: &str) -> Result<(Vec<Pattern>, Vec<Pattern>), ParseError> {
+    let mut lines_iter = input.lines();
+
+    let available_patterns = lines_iter.next()
+        .ok_or_else(|| ParseError::at_end(input, "expected a line of towel patterns"))?
+        .split(", ")
+        .map(|s| parse_colors(input, s))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match lines_iter.next() {
+        Some("") => (),
+        Some(line) => return Err(ParseError::at(input, line, "expected a blank line after the patterns")),
+        None => return Err(ParseError::at_end(input, "expected a blank line after the patterns")),
+    }
+
+    let designs = lines_iter
+        .map(|s| parse_colors(input, s))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((available_patterns, designs))
+}
+
+
+fn parse_colors(input: &str, s: &str) -> Result<Pattern, ParseError> {
+    s.char_indices()
+        .map(|(idx, c)| {
+            Color::from_char(c)
+                .ok_or_else(|| ParseError::at(input, &s[idx..], "expected a stripe color (`w`, `u`, `b`, `r` or `g`)"))
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EX: &str = include_str!("example");
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day19>();
+    }
+
+    #[test]
+    fn crosscheck() {
+        crate::differential::assert_agree::<Day19>();
+    }
+
+    #[test]
+    fn test1() {
+        let (available, designs) = parse_input(EX).unwrap();
+        let trie = generate_trie(&available);
+
+        let count = designs.iter()
+            .filter(|design| find_possible_combos(&trie, design) > 0)
+            .count();
+        assert_eq!(count, 6)
+    }
+
+}
\ No newline at end of file
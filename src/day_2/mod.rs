@@ -0,0 +1,149 @@
+use std::fmt::Write;
+
+use nom::{
+    character::complete::{digit1, char},
+    combinator::map_res,
+    multi::separated_list1,
+    IResult,
+};
+use itertools::Itertools;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<Vec<i32>>;
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("2").part2("4"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+}
+
+
+fn p1(reports: &[Vec<i32>]) -> Answer {
+    let safe_report_count = reports.iter()
+        .filter(|report| is_level_safe(report))
+        .count();
+
+    safe_report_count.into()
+}
+
+
+fn p2(reports: &[Vec<i32>]) -> Answer {
+    let safe_report_count = reports.iter()
+        .map(|report| {
+            if is_dampened_report_safe(report) {
+                1
+            } else {
+                0
+            }
+        })
+        .sum::<u32>();
+
+    safe_report_count.into()
+}
+
+
+fn is_level_safe(report: &[i32]) -> bool {
+    let diff = report.iter()
+        .tuple_windows()
+        .map(|(&a, &b)| {
+            b - a
+        })
+        .collect::<Vec<i32>>();
+
+    diff.iter().all(|&v| v > 0 && v < 4) || diff.iter().all(|&v| v < 0 && v > -4)
+}
+
+
+fn is_dampened_report_safe(report: &[i32]) -> bool {
+    if is_level_safe(report) {
+        return true
+    }
+
+    for idx in 0..report.len() {
+        let sliced_report = [&report[..idx], &report[idx+1..]].concat();
+        if is_level_safe(&sliced_report) {
+            return true
+        }
+    }
+
+    false
+}
+
+
+/// Reports that rise or fall gently, about half with one level knocked out
+/// of line.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let mut out = String::new();
+    for _ in 0..6 * scale {
+        let len = rng.range(5..9) as usize;
+        let sign = if rng.one_in(2) { 1 } else { -1 };
+        let mut levels = vec![rng.range(30..60)];
+        for _ in 1..len {
+            levels.push(levels.last().unwrap() + sign * rng.range(1..4));
+        }
+        if rng.one_in(2) {
+            let idx = rng.index(len);
+            levels[idx] = rng.range(1..90);
+        }
+        writeln!(out, "{}", levels.iter().join(" ")).unwrap();
+    }
+    out
+}
+
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input.lines()
+        .map(|line| parse_line(input, line))
+        .collect()
+}
+
+
+fn parse_line(input: &str, line: &str) -> Result<Vec<i32>, ParseError> {
+    let result = separated_list1(
+        char(' '), 
+        parse_number
+    )(line);
+    parse::finish(input, result)
+}
+
+
+fn parse_number(input: &str) -> IResult<&str, i32> {
+    map_res(digit1, |s: &str| s.parse::<i32>())(input)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day2>();
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,201 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::RangeInclusive;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{self, Generated, Rng};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::geom::Point;
+use crate::grid::Grid;
+use crate::search::bfs;
+
+#[derive(Debug)]
+pub enum MapObj {
+    Empty,
+    Wall,
+}
+
+fn get_cheats(map: &Grid<MapObj>, start: Point, stop: Point, cheat_length: i32) -> HashMap<(Point, Point), i32> {
+    let nominal = bfs(stop, |&pos| {
+        get_neighbors(map, pos).into_iter()
+            .filter(|(_, cheated)| !cheated)
+            .map(|(neighbor, _)| neighbor)
+    });
+    let nominal_cost_from = nominal.distances()
+        .iter()
+        .map(|(&pos, &cost)| (pos, cost as i32))
+        .collect::<HashMap<_, _>>();
+
+    crate::debug!("No cheat start to stop = {}", nominal_cost_from[&start]);
+    let mut cheats = HashMap::new();
+
+    for (&cheat_start, &start_cost) in nominal_cost_from.iter() {
+        let mut cheat_frontier = VecDeque::new();
+        let mut visited = HashSet::new();
+        cheat_frontier.push_back((cheat_start, cheat_length));
+        visited.insert(cheat_start);
+
+        while let Some((cur_pos, remaining_cheat_time)) = cheat_frontier.pop_front() {
+            if remaining_cheat_time == 0 { continue };
+
+            let new_remaining_cheat_time = remaining_cheat_time - 1;
+            for (neighbor, cheated) in get_neighbors(map, cur_pos) {
+                if new_remaining_cheat_time == 0 && cheated { continue }
+                if !visited.contains(&neighbor) {
+                    visited.insert(neighbor);
+                    cheat_frontier.push_back((neighbor, new_remaining_cheat_time));
+
+                    if let Some(&end_cost) = nominal_cost_from.get(&neighbor) {
+                        let cheat_time = cheat_length - new_remaining_cheat_time;
+                        let cost_change = start_cost - (end_cost + cheat_time);
+                        if cost_change > 0 {
+                            if let Some(&existing_change) = cheats.get(&(cheat_start, neighbor)) {
+                                if existing_change > cost_change { continue }
+                            }
+                            cheats.insert((cheat_start, neighbor), cost_change);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    cheats
+}
+
+
+fn get_neighbors(map: &Grid<MapObj>, pos: Point) -> Vec<(Point, bool)> {
+    pos.neighbors4()
+        .filter_map(|neighbor| Some((neighbor, matches!(map.get_point(neighbor)?, MapObj::Wall))))
+        .collect()
+}
+
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = (Grid<MapObj>, Point, Point);
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").params(&[("threshold", 20)]).part1("5"),
+        example!("example").params(&[("threshold", 76)]).part2("3"),
+    ];
+
+    const PARAMS: &'static [(&'static str, i64)] = &[
+        ("threshold", 100),
+        ("short_cheat", 2),
+        ("long_cheat", 20),
+    ];
+
+    const PARAM_RANGES: &'static [(&'static str, RangeInclusive<i64>)] = &[
+        ("threshold", 0..=(i32::MAX as i64)),
+        ("short_cheat", 0..=(i32::MAX as i64)),
+        ("long_cheat", 0..=(i32::MAX as i64)),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, params: &Params) -> Answer {
+        p1(input, params.get("threshold") as i32, params.get("short_cheat") as i32)
+    }
+
+    fn part2(input: &Self::Parsed, params: &Params) -> Answer {
+        p2(input, params.get("threshold") as i32, params.get("long_cheat") as i32)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale))
+    }
+}
+
+
+fn p1((map, start, stop): &(Grid<MapObj>, Point, Point), threshold: i32, cheat_length: i32) -> Answer {
+    let cheats = get_cheats(map, *start, *stop, cheat_length);
+
+    let count = cheats.into_iter()
+        .filter(|(_, cost)| *cost >= threshold)
+        .count();
+
+    count.into()
+}
+
+
+fn p2((map, start, stop): &(Grid<MapObj>, Point, Point), threshold: i32, cheat_length: i32) -> Answer {
+    let cheats = get_cheats(map, *start, *stop, cheat_length);
+
+    let count = cheats.into_iter()
+        .filter(|(_, cost)| *cost >= threshold)
+        .count();
+
+    count.into()
+}
+
+
+/// A single racetrack along the longest path through a random maze from
+/// its top left corner, with every tile off it walled. The example's size
+/// at scale 1, solved with a `threshold` that grows with `scale`.
+fn generate_input(rng: &mut Rng, scale: u32) -> Generated {
+    let rooms = 7 * scale as usize;
+    let walls = generate::maze(rng, rooms, rooms);
+    let start = (1, 1);
+    let search = bfs(start, |&pos| {
+        walls.neighbors4(pos).filter(|&next| !walls[next]).collect::<Vec<_>>()
+    });
+    let (&end, _) = search.distances().iter()
+        .max_by_key(|&(pos, dist)| (dist, pos))
+        .unwrap();
+    let track = search.path_to(&end).unwrap().into_iter().collect::<HashSet<_>>();
+
+    let input = walls.render(|pos, _| match pos {
+        _ if pos == start => 'S',
+        _ if pos == end => 'E',
+        _ if track.contains(&pos) => '.',
+        _ => '#',
+    });
+    Generated::from(input).with_params(&[("threshold", 10 * scale as i64)])
+}
+
+
+fn parse_input(input: &str) -> Result<(Grid<MapObj>, Point, Point), ParseError> {
+    let chars = Grid::parse(input, |c| matches!(c, '.' | '#' | 'S' | 'E').then_some(c))?;
+    let start = Point::from_idx(chars.find_unique(input, &'S', "start (`S`)")?);
+    let end = Point::from_idx(chars.find_unique(input, &'E', "end (`E`)")?);
+
+    let map = chars.map(|&c| match c {
+        '#' => MapObj::Wall,
+        _ => MapObj::Empty,
+    });
+    Ok((map, start, end))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day20>();
+    }
+
+    #[test]
+    fn test_out_of_range_params() {
+        let input = parse_input(include_str!("example")).unwrap();
+        let solve = |threshold, cheat| {
+            let params = Day20::default_params()
+                .with_overrides([("threshold", threshold), ("short_cheat", cheat), ("long_cheat", cheat)])?;
+            Ok::<_, String>((Day20::part1(&input, &params).to_string(), Day20::part2(&input, &params).to_string()))
+        };
+        assert_eq!(solve(20, 2), Ok(("5".to_string(), "5".to_string())));
+        assert_eq!(solve(0, 0), Ok(("0".to_string(), "0".to_string())));
+        assert!(solve(-1, 2).is_err());
+        assert!(solve(20, 1 << 40).is_err());
+        assert!(solve(1 << 31, 20).is_err());
+    }
+
+}
@@ -0,0 +1,187 @@
+use std::fmt::Write;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, char, anychar},
+    combinator::{map_res, map},
+    sequence::{separated_pair, delimited},
+    multi::{many0, many_till},
+    IResult
+};
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+
+#[derive(Debug)]
+pub enum Instr {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<Instr>;
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("161"),
+        example!("example2").part2("48"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+}
+
+
+fn p1(instrs: &[Instr]) -> Answer {
+    let sum = get_mult_sum(instrs);
+
+    sum.into()
+}
+
+
+fn p2(instrs: &[Instr]) -> Answer {
+    let sum = get_mult_sum_p2(instrs);
+
+    sum.into()
+}
+
+
+fn get_mult_sum(instrs: &[Instr]) -> u32 {
+    instrs.iter()
+        .filter_map(|instr| {
+            match instr {
+                Instr::Mul(a, b) => Some((a, b)),
+                _ => None
+            }
+        })
+        .map(|(a, b)| a*b)
+        .sum::<u32>()
+}
+
+
+fn get_mult_sum_p2(instrs: &[Instr]) -> u32 {
+    let mut enabled = true;
+    let mut sum = 0;
+    for instr in instrs.iter() {
+        match instr {
+            Instr::Mul(a, b) => if enabled {sum += a * b},
+            Instr::Do => enabled = true,
+            Instr::Dont => enabled = false,
+        }
+    }
+    sum
+}
+
+
+/// Corrupted memory: `mul`, `do()` and `don't()` instructions among junk,
+/// including near misses that must not count.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    const JUNK: &[&str] = &[
+        "mul(4*", "mul ( 2 , 4 )", "mul[3,7]", "mul(32,64]", "mul(1234,5)", "?(12,34)",
+        "don't", "from()", "what()", "select()", ")", "'", "%", "&", "@", "^", "[", "<", " ",
+    ];
+
+    let mut out = String::new();
+    for _ in 0..scale {
+        for _ in 0..16 {
+            match rng.below(8) {
+                0 => out.push_str("do()"),
+                1 => out.push_str("don't()"),
+                2 | 3 => write!(out, "mul({},{})", rng.range(1..1000), rng.range(1..1000)).unwrap(),
+                _ => out.push_str(JUNK[rng.index(JUNK.len())]),
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+
+fn parse_input(input: &str) -> Result<Vec<Instr>, ParseError> {
+    // Anything after the last instruction is corrupted memory, not an error
+    let (_, instrs) = many0(parse_next_instr)
+    (input)
+        .map_err(|err| parse::nom_error(input, err))?;
+    Ok(instrs)
+}
+
+
+fn parse_next_instr(input: &str) -> IResult<&str, Instr> {
+    map(
+        many_till(
+            anychar, 
+            alt((parse_mul, parse_do, parse_dont))
+        ),
+        |(_, m)| m
+    )(input)
+}
+
+fn parse_mul(input: &str) -> IResult<&str, Instr> {
+    map(
+        delimited(
+            tag("mul("), 
+            separated_pair(
+                parse_number, 
+                char(','), 
+                parse_number
+            ),
+            char(')')
+        ),
+        |(a,b)| Instr::Mul(a, b)
+    )(input)
+}
+
+
+fn parse_do(input: &str) -> IResult<&str, Instr> {
+    map(tag("do()"), |_| Instr::Do)(input)
+}
+
+fn parse_dont(input: &str) -> IResult<&str, Instr> {
+    map(tag("don't()"), |_| Instr::Dont)(input)
+}
+
+
+fn parse_number(input: &str) -> IResult<&str, u32> {
+    map_res(digit1, |s: &str| s.parse::<u32>())(input)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EX: &str = include_str!("example");
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day3>();
+    }
+
+    #[test]
+    fn test1() {
+        let instrs = parse_input(EX).unwrap();
+        assert_eq!(get_mult_sum(&instrs), 161);
+    }
+
+}
\ No newline at end of file
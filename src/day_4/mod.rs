@@ -0,0 +1,162 @@
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::grid::Grid;
+
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Grid<char>;
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("18").part2("9"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+}
+
+
+fn p1(grid: &Grid<char>) -> Answer {
+    let count = search_grid_p1(grid);
+    count.into()
+}
+
+
+fn p2(grid: &Grid<char>) -> Answer {
+    let count = search_grid_p2(grid);
+    count.into()
+}
+
+
+fn search_grid_p1(grid: &Grid<char>) -> u32 {
+    let mut count = 0;
+    for x in 0..grid.ncols() {
+        for y in 0..grid.nrows() {
+            count += xmas_count(grid, x, y);
+        }
+    }
+    count
+}
+
+
+fn search_grid_p2(grid: &Grid<char>) -> u32 {
+    let mut count = 0;
+    for x in 1..grid.ncols()-1 {
+        for y in 1..grid.nrows()-1 {
+            if has_x_mas(grid, x, y) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+
+fn xmas_count(grid: &Grid<char>, x: usize, y: usize) -> u32 {
+    const DELTAS: [[(isize, isize); 4]; 8] = [
+        [( 0, 0),( 1, 0),( 2, 0),( 3, 0)],
+        [( 0, 0),(-1, 0),(-2, 0),(-3, 0)],
+        [( 0, 0),( 0, 1),( 0, 2),( 0, 3)],
+        [( 0, 0),( 0,-1),( 0,-2),( 0,-3)],
+        [( 0, 0),( 1, 1),( 2, 2),( 3, 3)],
+        [( 0, 0),(-1,-1),(-2,-2),(-3,-3)],
+        [( 0, 0),( 1,-1),( 2,-2),( 3,-3)],
+        [( 0, 0),(-1, 1),(-2, 2),(-3, 3)]
+    ];
+
+    const XMAS: [char; 4] = ['X','M','A','S'];
+
+    let x = x as isize;
+    let y = y as isize;
+
+    let mut count = 0;
+    for direction in DELTAS.into_iter() {
+        let mut valid_word = true;
+        for (to_find, (dx, dy)) in std::iter::zip(XMAS, direction) {
+            let Some(&c) = grid.get_signed(y+dy, x+dx) else {
+                valid_word = false;
+                break
+            };
+            if c != to_find {
+                valid_word = false;
+                break
+            }
+        }
+        if valid_word {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+
+fn has_x_mas(grid: &Grid<char>, x: usize, y: usize) -> bool {
+    let x = x as isize;
+    let y = y as isize;
+
+    let Some(&val) = grid.get_signed(y, x) else { return false };
+    if val != 'A' { return false };
+
+    let Some(&ul) = grid.get_signed(y+1, x-1) else { return false };
+    let Some(&ll) = grid.get_signed(y-1, x-1) else { return false };
+    let Some(&ur) = grid.get_signed(y+1, x+1) else { return false };
+    let Some(&lr) = grid.get_signed(y-1, x+1) else { return false };
+
+    if !((ul == 'M' && lr == 'S') || (ul == 'S' && lr == 'M')) { return false };
+    if !((ll == 'M' && ur == 'S') || (ll == 'S' && ur == 'M')) { return false };
+
+    true
+}
+
+
+/// A square of random `XMAS` letters.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let size = 10 * scale as usize;
+    let letters = (0..size * size).map(|_| *rng.pick(&['X', 'M', 'A', 'S'])).collect();
+    Grid::from_vec(size, size, letters).to_string()
+}
+
+
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, Some)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EX: &str = include_str!("example");
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day4>();
+    }
+
+    #[test]
+    fn test1() {
+        assert_eq!(18, search_grid_p1(&parse_input(EX).unwrap()));
+        assert_eq!(9, search_grid_p2(&parse_input(EX).unwrap()));
+    }
+
+}
\ No newline at end of file
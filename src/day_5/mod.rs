@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+
+#[derive(Debug)]
+pub struct OrderRule {
+    first: u32,
+    last: u32,
+}
+
+enum UpdateStatus {
+    Correct(u32),
+    Incorrect(u32),
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = (Vec<OrderRule>, Vec<Vec<u32>>);
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("143").part2("123"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+}
+
+
+fn check_update(rules: &[OrderRule], update: &[u32]) -> UpdateStatus {
+    let mut update = update.to_vec();
+    let mut update_hash = HashMap::new();
+    for (idx, page) in update.iter().enumerate() {
+        update_hash.insert(*page, idx);
+    }
+
+    let mut init_correct = true;
+
+    'sort_loop: loop {
+        for rule in rules.iter() {
+            let Some(&first_idx) = update_hash.get(&rule.first) else { continue };
+            let Some(&last_idx) = update_hash.get(&rule.last) else { continue };
+            if first_idx > last_idx {
+                init_correct = false;
+                update.swap(first_idx, last_idx);
+                update_hash.insert(rule.first, last_idx);
+                update_hash.insert(rule.last, first_idx);
+                continue 'sort_loop
+            }
+        }
+        break
+    }
+
+    if init_correct {
+        UpdateStatus::Correct(update[update.len()/2])
+    } else {
+        UpdateStatus::Incorrect(update[update.len()/2])
+    }
+}
+
+
+fn p1((rules, updates): &(Vec<OrderRule>, Vec<Vec<u32>>)) -> Answer {
+    let sum = updates.iter()
+        .filter_map(|update| {
+            match check_update(rules, update) {
+                UpdateStatus::Correct(middle) => Some(middle),
+                UpdateStatus::Incorrect(_) => None,        
+            }
+        })
+        .sum::<u32>();
+    sum.into()
+}
+
+
+fn p2((rules, updates): &(Vec<OrderRule>, Vec<Vec<u32>>)) -> Answer {
+    let sum = updates.iter()
+        .filter_map(|update| {
+            match check_update(rules, update) {
+                UpdateStatus::Correct(_) => None,
+                UpdateStatus::Incorrect(middle) => Some(middle),        
+            }
+        })
+        .sum::<u32>();
+    sum.into()
+}
+
+
+/// Rules for every pair of pages, all following one hidden order so fixing
+/// an update always terminates, then updates of an odd number of pages,
+/// about half of them already in order.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let mut pages = (10..100).collect::<Vec<_>>();
+    rng.shuffle(&mut pages);
+    pages.truncate((7 * scale as usize).min(pages.len()));
+
+    let mut rules = pages.iter().enumerate()
+        .flat_map(|(idx, first)| pages[idx + 1..].iter().map(move |last| (first, last)))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut rules);
+
+    let mut out = String::new();
+    for (first, last) in rules {
+        writeln!(out, "{first}|{last}").unwrap();
+    }
+    out.push('\n');
+
+    let max_len = pages.len().min(23) as i64;
+    for _ in 0..6 * scale {
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(2 * rng.range(1..(max_len + 1) / 2) as usize + 1);
+        if rng.one_in(2) {
+            update.sort_by_key(|page| pages.iter().position(|p| p == page));
+        }
+        writeln!(out, "{}", update.iter().join(",")).unwrap();
+    }
+    out
+}
+
+
+fn parse_input(input: &str) -> Result<(Vec<OrderRule>, Vec<Vec<u32>>), ParseError> {
+    let mut line_iter = input.lines();
+
+    let mut order_rules = Vec::new();
+
+    for line in line_iter.by_ref() {
+        if line.is_empty() { break }
+        let (first, last) = line.split_once('|')
+            .ok_or_else(|| ParseError::at(input, line, "expected an ordering rule like `47|53`"))?;
+        order_rules.push(OrderRule {
+            first: parse::number(input, first)?,
+            last: parse::number(input, last)?
+        });
+    }
+
+    let page_updates = line_iter
+        .map(|line| {
+            line.split(',')
+                .map(|s| parse::number(input, s))
+                .collect::<Result<Vec<u32>, _>>()
+        })
+        .collect::<Result<Vec<Vec<u32>>, _>>()?;
+
+    Ok((order_rules, page_updates))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EX: &str = include_str!("example");
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day5>();
+    }
+
+    #[test]
+    fn test1() {
+        let (rules, updates) = parse_input(EX).unwrap();
+        let mut correct_sum = 0;
+        let mut incorrect_sum = 0;
+        for update in updates {
+            match check_update(&rules, &update) {
+                UpdateStatus::Correct(middle) => correct_sum += middle,
+                UpdateStatus::Incorrect(middle) => incorrect_sum += middle,
+            }
+        }
+        assert_eq!(correct_sum, 143);
+        assert_eq!(incorrect_sum, 123);
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::cancel;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::geom::{Dir, Point};
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Cell {
+    Obstacle,
+    Free,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pos: Point,
+    dir: Dir,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = (Grid<Cell>, Guard);
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("41").part2("6"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+}
+
+
+fn p1((grid, guard): &(Grid<Cell>, Guard)) -> Answer {
+    match walk_map(grid, *guard) {
+        Some(visited) => visited.len().into(),
+        None => "guard never leaves".into(),
+    }
+}
+
+
+fn p2((grid, guard): &(Grid<Cell>, Guard)) -> Answer {
+    let starting_pos = guard.pos;
+    let Some(visited) = walk_map(grid, *guard) else {
+        return "guard never leaves".into()
+    };
+
+    let mut blockers = 0;
+    for pos in visited.iter() {
+        if starting_pos == *pos { continue }
+        let mut mod_grid = grid.clone();
+        *mod_grid.get_point_mut(*pos).unwrap() = Cell::Obstacle;
+        if walk_map(&mod_grid, *guard).is_none() {
+            blockers += 1;
+        }
+    }
+
+    blockers.into()
+}
+
+
+fn walk_map(grid: &Grid<Cell>, mut guard: Guard) -> Option<HashSet<Point>> {
+    let mut visited_pos = HashSet::new();
+
+    loop {
+        cancel::check();
+        if !visited_pos.insert((guard.pos, guard.dir)) {
+            return None
+        }
+
+        let new_pos = guard.pos + guard.dir.delta();
+        if let Some(Cell::Obstacle) = grid.get_point(new_pos) {
+            guard.dir = guard.dir.turn_right();
+        } else {
+            guard.pos = new_pos;
+        }
+
+        if grid.get_point(guard.pos).is_none() {
+            return Some(visited_pos.into_iter()
+                .map(|(pos, _)| pos)
+                .collect()
+            )
+        }
+    }
+}
+
+
+/// A lab with scattered obstacles and the guard on a free tile, retried
+/// until the guard walks off the map rather than round in a loop.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let size = 10 * scale as usize;
+    loop {
+        let cells = (0..size * size)
+            .map(|_| if rng.one_in(10) { Cell::Obstacle } else { Cell::Free })
+            .collect();
+        let grid = Grid::from_vec(size, size, cells);
+        let start = (rng.index(size), rng.index(size));
+        if matches!(grid[start], Cell::Obstacle) { continue }
+
+        let guard = Guard {
+            pos: Point::from_idx(start),
+            dir: Dir::North,
+        };
+        if walk_map(&grid, guard).is_some() {
+            return grid.render(|pos, cell| match cell {
+                _ if pos == start => '^',
+                Cell::Obstacle => '#',
+                Cell::Free => '.',
+            })
+        }
+    }
+}
+
+
+fn parse_input(input: &str) -> Result<(Grid<Cell>, Guard), ParseError> {
+    let chars = Grid::parse(input, |c| matches!(c, '#' | '.' | '^').then_some(c))?;
+    let start = chars.find_unique(input, &'^', "guard (`^`)")?;
+
+    let grid = chars.map(|&c| match c {
+        '#' => Cell::Obstacle,
+        _ => Cell::Free,
+    });
+    let guard = Guard {
+        pos: Point::from_idx(start),
+        dir: Dir::North,
+    };
+
+    Ok((grid, guard))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EX: &str = include_str!("example");
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day6>();
+    }
+
+    #[test]
+    fn test1() {
+        let (grid, guard) = parse_input(EX).unwrap();
+        let visited = walk_map(&grid, guard).unwrap();
+        assert_eq!(visited.len(), 41)
+    }
+
+    #[test]
+    fn test_never_leaves() {
+        let input = parse_input(".#..\n...#\n#^..\n..#.\n").unwrap();
+        assert_eq!(p1(&input).to_string(), "guard never leaves");
+        assert_eq!(p2(&input).to_string(), "guard never leaves");
+    }
+
+}
\ No newline at end of file
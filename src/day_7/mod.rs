@@ -0,0 +1,162 @@
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::cancel;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+
+
+#[derive(Debug)]
+pub struct Equation {
+    answer: u64,
+    inputs: Vec<u64>,
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Vec<Equation>;
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("3749").part2("11387"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+}
+
+
+fn p1(equations: &[Equation]) -> Answer {
+    let sum = equations.iter()
+        .filter(|eq| can_eval(eq.answer, eq.inputs.clone(), false))
+        .map(|eq| eq.answer)
+        .sum::<u64>();
+
+    sum.into()
+}
+
+
+fn p2(equations: &[Equation]) -> Answer {
+    let sum = equations.iter()
+        .filter(|eq| can_eval(eq.answer, eq.inputs.clone(), true))
+        .map(|eq| eq.answer)
+        .sum::<u64>();
+
+    sum.into()
+}
+
+
+fn can_eval(ans: u64, mut inputs: Vec<u64>, use_concat: bool) -> bool {
+    cancel::check();
+    let input = inputs.pop().unwrap();
+    if inputs.is_empty() {
+        return input == ans
+    }
+    let mult_branch = if ans.is_multiple_of(input) {
+        can_eval(ans/input, inputs.clone(), use_concat)
+    } else {
+        false
+    };
+    let add_branch = if ans >= input {
+        can_eval(ans-input, inputs.clone(), use_concat)
+    } else { 
+        false
+    };
+    let concat_branch = if use_concat {
+        let ans_str = format!("{ans}");
+        let input_str = format!("{input}");
+        if ans_str.len() > input_str.len() && ans_str.ends_with(&input_str) {
+            let new_ans = ans_str[..(ans_str.len()-input_str.len())].parse::<u64>().unwrap();
+            can_eval(new_ans, inputs.clone(), use_concat)
+        } else {
+            false
+        }
+    } else {
+        false
+    };
+
+    mult_branch || add_branch || concat_branch
+}
+
+
+/// Equations whose answers can usually be made with the three operators,
+/// and are otherwise nudged off by a little.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let mut out = String::new();
+    for _ in 0..9 * scale {
+        let inputs = (0..rng.range(2..7)).map(|_| rng.range(1..100) as u64).collect::<Vec<_>>();
+        let mut answer = inputs[0];
+        for &input in inputs[1..].iter() {
+            answer = match rng.below(3) {
+                0 => answer + input,
+                1 => answer * input,
+                _ => format!("{answer}{input}").parse().unwrap(),
+            };
+        }
+        if rng.one_in(2) {
+            answer += rng.range(1..10) as u64;
+        }
+        writeln!(out, "{answer}: {}", inputs.iter().join(" ")).unwrap();
+    }
+    out
+}
+
+
+fn parse_input(input: &str) -> Result<Vec<Equation>, ParseError> {
+    input.lines()
+        .map(|line| {
+            let (ans_str, inp_str) = line.split_once(':')
+                .ok_or_else(|| ParseError::at(input, line, "expected an equation like `190: 10 19`"))?;
+            let answer = parse::number(input, ans_str)?;
+            let inputs = inp_str.split_whitespace()
+                .map(|s| parse::number(input, s))
+                .collect::<Result<Vec<_>, _>>()?;
+            if inputs.is_empty() {
+                return Err(ParseError::at(input, inp_str, "expected at least one number after `:`"))
+            }
+            Ok(Equation {
+                answer,
+                inputs
+            })
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day7>();
+    }
+
+    #[test]
+    fn test_no_operands() {
+        let err = parse_input("3267: 81 40 27\n190:\n").unwrap_err();
+        assert_eq!((err.line, err.col), (2, 5));
+        assert!(err.message.contains("at least one number"), "{err}");
+    }
+
+}
+
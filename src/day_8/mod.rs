@@ -0,0 +1,189 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::grid::{Grid, Idx};
+
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = Grid<Option<char>>;
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("14").part2("34"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
+}
+
+
+fn p1(grid: &Grid<Option<char>>) -> Answer {
+    let anodes = get_antinodes_p1(grid);
+    anodes.len().into()
+}
+
+
+fn p2(grid: &Grid<Option<char>>) -> Answer {
+    let anodes = get_antinodes_p2(grid);
+    anodes.len().into()
+}
+
+
+/// The positions of the antennas on each frequency.
+fn antennas(grid: &Grid<Option<char>>) -> HashMap<char, Vec<Idx>> {
+    let mut freq_locs = HashMap::new();
+    for (coords, &freq) in grid.indexed_iter() {
+        if let Some(freq) = freq {
+            freq_locs.entry(freq)
+                .or_insert(vec![])
+                .push(coords);
+        }
+    }
+    freq_locs
+}
+
+
+fn get_antinodes_p1(grid: &Grid<Option<char>>) -> HashSet<(usize,usize)> {
+    let freq_locs = antennas(grid);
+
+    let mut anodes = HashSet::new();
+
+    for coord_list in freq_locs.values() {
+        for (c1, c2) in coord_list.iter().tuple_combinations() {
+            let c1 = (c1.0 as isize, c1.1 as isize);
+            let c2 = (c2.0 as isize, c2.1 as isize);
+
+            let node1 = (
+                c1.0 - (c2.0 - c1.0),
+                c1.1 - (c2.1 - c1.1)
+            );
+            let node2 = (
+                c2.0 - (c1.0 - c2.0),
+                c2.1 - (c1.1 - c2.1)
+            );
+
+            for node in [node1, node2] {
+                if grid.get_signed(node.0, node.1).is_some() {
+                    anodes.insert((node.0 as usize, node.1 as usize));
+                }
+            }
+        }
+    }
+
+    anodes
+}
+
+
+fn get_antinodes_p2(grid: &Grid<Option<char>>) -> HashSet<(usize,usize)> {
+    let freq_locs = antennas(grid);
+
+    let mut anodes = HashSet::new();
+
+    for coord_list in freq_locs.values() {
+        for (c1, c2) in coord_list.iter().tuple_combinations() {
+            let c1 = (c1.0 as isize, c1.1 as isize);
+            let c2 = (c2.0 as isize, c2.1 as isize);
+
+            let row_delta = c2.0 - c1.0;
+            let col_delta = c2.1 - c1.1;
+
+            let mut i = 1;
+            anodes.insert((c1.0 as usize, c1.1 as usize));
+            let (mut anode1_valid, mut anode2_valid) = (true, true);
+            while anode1_valid || anode2_valid {
+                if anode1_valid {
+                    let anode1 = (c1.0 - row_delta*i, c1.1 - col_delta*i);
+                    if grid.get_signed(anode1.0, anode1.1).is_some() {
+                        anodes.insert((anode1.0 as usize, anode1.1 as usize));
+                    } else {
+                        anode1_valid = false;
+                    }
+                }
+                
+                if anode2_valid {
+                    let anode2 = (c1.0 + row_delta*i, c1.1 + col_delta*i);
+                    if grid.get_signed(anode2.0, anode2.1).is_some() {
+                        anodes.insert((anode2.0 as usize, anode2.1 as usize));
+                    } else {
+                        anode2_valid = false;
+                    }
+                }
+
+                i += 1;
+            }
+        }
+    }
+
+    anodes
+}
+
+
+/// A city with a few antennas on each of a handful of frequencies.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let size = 12 * scale as usize;
+    let mut frequencies = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+    rng.shuffle(&mut frequencies);
+
+    let mut grid = Grid::filled(size, size, '.');
+    for &frequency in frequencies.iter().take(2 * scale as usize) {
+        for _ in 0..rng.range(3..6) {
+            let pos = (rng.index(size), rng.index(size));
+            grid[pos] = frequency;
+        }
+    }
+    grid.to_string()
+}
+
+
+/// The map, with the frequency of the antenna in each cell that has one.
+fn parse_input(input: &str) -> Result<Grid<Option<char>>, ParseError> {
+    Grid::parse(input, |c| match c {
+        '.' => Some(None),
+        _ => c.is_ascii_alphanumeric().then_some(Some(c)),
+    })
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EX: &str = include_str!("example");
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day8>();
+    }
+
+    #[test]
+    fn test1() {
+        let grid = parse_input(EX).unwrap();
+        let anodes = get_antinodes_p1(&grid);
+        assert_eq!(anodes.len(), 14);
+
+        let anodes = get_antinodes_p2(&grid);
+        assert_eq!(anodes.len(), 34);
+    }
+}
\ No newline at end of file
@@ -1,7 +1,13 @@
-use std::time::Instant;
-
 use rangemap::RangeMap;
 
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::ParseError;
+
 #[derive(Debug)]
 struct DiskEntity {
     pos: usize,
@@ -13,47 +19,48 @@ struct Disk {
     disk_map: Vec<Option<usize>>,
 }
 
-fn main() {
-    let input = include_str!("input");
+pub struct Day9;
 
-    let start = Instant::now();
-    let _parsed_input = parse_input(input);
-    let parse_duration = start.elapsed().as_secs_f32();
-    println!("Parsing took {parse_duration} secs");
+impl Solution for Day9 {
+    type Parsed = Vec<u8>;
 
-    println!();
+    const EXAMPLES: &'static [Example] = &[
+        example!("example").part1("1928").part2("2858"),
+    ];
 
-    let start = Instant::now();
-    let p1_answer = p1(input);
-    let p1_duration = start.elapsed().as_secs_f32();
-    println!("P1: {p1_answer}");
-    println!("Took {p1_duration} secs");
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
 
-    println!();
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
 
-    let start = Instant::now();
-    let p2_answer = p2(input);
-    let p2_duration = start.elapsed().as_secs_f32();
-    println!("P2: {p2_answer}");
-    println!("Took {p2_duration} secs");
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: u32) -> Option<Generated> {
+        Some(generate_input(rng, scale).into())
+    }
 }
 
 
-fn p1(input: &str) -> String {
-    let disk = build_disk(&parse_input(input));
+fn p1(disk_layout: &[u8]) -> Answer {
+    let disk = build_disk(disk_layout);
     let disk_map = compress(disk.disk_map);
     let checksum = get_checksum(&disk_map);
 
-    format!("{checksum}")
+    checksum.into()
 }
 
 
-fn p2(input: &str) -> String {
-    let disk = build_disk(&parse_input(input));
+fn p2(disk_layout: &[u8]) -> Answer {
+    let disk = build_disk(disk_layout);
     let disk_map = defrag(disk);
     let checksum = get_checksum(&disk_map);
 
-    format!("{checksum}")
+    checksum.into()
 }
 
 
@@ -61,7 +68,7 @@ fn build_disk(input: &[u8]) -> Disk {
     let mut files = vec![];
     let mut free_space = vec![];
 
-    let mut input_iter = input.into_iter();
+    let mut input_iter = input.iter();
     let mut disk_idx = 0;
 
     loop {
@@ -90,9 +97,7 @@ fn build_disk(input: &[u8]) -> Disk {
     let mut disk_map: Vec<Option<usize>> = vec![None; disk_size];
 
     for (id, file) in files.iter().enumerate() {
-        for idx in file.pos..(file.pos+file.size) {
-            disk_map[idx] = Some(id)
-        }
+        disk_map[file.pos..(file.pos+file.size)].fill(Some(id));
     }
 
     Disk {
@@ -147,11 +152,7 @@ fn defrag(disk: Disk) -> Vec<Option<usize>> {
 
     let disk_map = (0..range_map.last_range_value().unwrap().0.end)
         .map(|idx| {
-            if let Some(id) = range_map.get(&idx) {
-                Some(*id)
-            } else {
-                None
-            }
+            range_map.get(&idx).copied()
         })
         .collect();  
 
@@ -163,26 +164,30 @@ fn get_checksum(disk_map: &[Option<usize>]) -> usize {
     disk_map.iter()
         .enumerate()
         .filter_map(|(idx, item)| {
-            if let Some(id) = *item {
-                Some(id * idx)
-            } else {
-                None
-            }
+            (*item).map(|id| id * idx)
         })
         .sum::<usize>()
 }
 
 
-fn parse_input(input: &str) -> Vec<u8> {
-    input.trim()
-        .chars()
-        .map(|c| {
-            if (c as u8) >= ('0' as u8) {
-                c as u8 - ('0' as u8)
-            } else {
-                unimplemented!("Unexpected char {c}")
-            }
-            
+/// A disk map alternating files and gaps, starting and ending with a file.
+fn generate_input(rng: &mut Rng, scale: u32) -> String {
+    let mut out = (0..10 * scale)
+        .map(|_| format!("{}{}", rng.range(1..10), rng.range(0..10)))
+        .collect::<String>();
+    out.pop();
+    out.push('\n');
+    out
+}
+
+
+fn parse_input(input: &str) -> Result<Vec<u8>, ParseError> {
+    let disk_map = input.trim();
+    disk_map.char_indices()
+        .map(|(idx, c)| {
+            c.to_digit(10)
+                .map(|digit| digit as u8)
+                .ok_or_else(|| ParseError::at(input, &disk_map[idx..], format!("expected a digit, found {c:?}")))
         })
         .collect()
 }
@@ -194,42 +199,34 @@ mod test {
 
     const EX: &str = include_str!("example");
 
+    fn render(disk_map: &[Option<usize>]) -> String {
+        disk_map.iter()
+            .map(|item| item.map_or('.', |id| char::from_digit(id as u32, 10).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<Day9>();
+    }
+
     #[test]
     fn test1() {
-        let disk = build_disk(&parse_input(EX));
-        //let disk_map = build_disc_map(&parse_input(EX));
+        let disk = build_disk(&parse_input(EX).unwrap());
         let disk_map = disk.disk_map;
-        for item in disk_map.iter() {
-            match item {
-                Some(id) => print!("{id}"),
-                None => print!("."),
-            }
-        }
-        println!();
+        assert_eq!(render(&disk_map), "00...111...2...333.44.5555.6666.777.888899");
 
         let disk_map = compress(disk_map);
-        for item in disk_map.iter() {
-            match item {
-                Some(id) => print!("{id}"),
-                None => print!("."),
-            }
-        }
-        println!();
+        assert_eq!(render(&disk_map), "0099811188827773336446555566..............");
 
         assert_eq!(get_checksum(&disk_map), 1928)
     }
 
     #[test]
     fn test2() {
-        let disk = build_disk(&parse_input(EX));
+        let disk = build_disk(&parse_input(EX).unwrap());
         let disk_map = defrag(disk);
-        for item in disk_map.iter() {
-            match item {
-                Some(id) => print!("{id}"),
-                None => print!("."),
-            }
-        }
-        println!();
+        assert_eq!(render(&disk_map), "00992111777.44.333....5555.6666.....8888");
 
         assert_eq!(get_checksum(&disk_map), 2858)
     }
@@ -0,0 +1,56 @@
+use crate::Solution;
+use crate::answer::Answer;
+use crate::example;
+use crate::examples::Example;
+use crate::params::Params;
+use crate::parse::ParseError;
+
+
+pub struct DayX;
+
+impl Solution for DayX {
+    type Parsed = ();
+
+    const EXAMPLES: &'static [Example] = &[
+        example!("example"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+        p2(input)
+    }
+}
+
+
+fn p1(_input: &()) -> Answer {
+    0.into()
+}
+
+
+fn p2(_input: &()) -> Answer {
+    0.into()
+}
+
+
+fn parse_input(_input: &str) -> Result<(), ParseError> {
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        crate::examples::assert_pass::<DayX>();
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,380 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{LazyLock, Once};
+use std::time::Duration;
+
+use regex::Regex;
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::cancel::{self, Cancelled};
+use crate::generate::Rng;
+use crate::params::Params;
+use crate::parse::ParseError;
+
+static NUMBER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\d+").unwrap());
+
+thread_local! {
+    /// Set on threads whose panics are part of the check, so the panic hook
+    /// keeps quiet about them.
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Which generated inputs to cross-check a day on.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Seed of the first input. Each following input uses the next seed.
+    pub seed: u64,
+    pub cases: u64,
+    pub scale: u32,
+    /// How long either solver may spend on one input.
+    pub budget: Duration,
+    /// Parameter overrides, applied after the day's reference parameters.
+    pub overrides: Vec<(String, i64)>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            cases: 100,
+            scale: 1,
+            budget: Duration::from_secs(5),
+            overrides: vec![],
+        }
+    }
+}
+
+
+/// What a solver made of one input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(String),
+    Panicked(String),
+    TimedOut,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{answer}"),
+            Outcome::Panicked(message) => write!(f, "panicked: {message}"),
+            Outcome::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+
+/// Every generated input got the same answers from both solvers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Agreement {
+    pub cases: u64,
+    /// The parts that have a reference solver and so were compared.
+    pub parts: Vec<u8>,
+}
+
+/// An input on which a part disagrees with its reference, shrunk as far as
+/// it would go while still disagreeing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    /// The seed of the generated input this was shrunk from.
+    pub seed: u64,
+    pub part: u8,
+    pub input: String,
+    pub params: Vec<(String, i64)>,
+    /// What the reference solver made of the input.
+    pub expected: Outcome,
+    pub actual: Outcome,
+}
+
+impl fmt::Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "part {} disagrees with its reference on input from seed {}, shrunk to:", self.part, self.seed)?;
+        for line in self.input.lines() {
+            writeln!(f, "    {line}")?;
+        }
+        if !self.params.is_empty() {
+            let params = self.params.iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect::<Vec<_>>();
+            writeln!(f, "params:    {}", params.join(" "))?;
+        }
+        writeln!(f, "reference: {}", self.expected)?;
+        write!(f, "solver:    {}", self.actual)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckError {
+    NoGenerator,
+    Params(String),
+    /// A generated input failed to parse, which is a bug in the generator.
+    Parse { seed: u64, error: ParseError },
+    Disagreement(Box<Counterexample>),
+}
+
+impl fmt::Display for CheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckError::NoGenerator => write!(f, "no input generator"),
+            CheckError::Params(err) => write!(f, "{err}"),
+            CheckError::Parse { seed, error } => write!(f, "generated input from seed {seed} doesn't parse: {error}"),
+            CheckError::Disagreement(counterexample) => write!(f, "{counterexample}"),
+        }
+    }
+}
+
+impl std::error::Error for CheckError {}
+
+
+/// Runs both parts and their reference solvers on `config.cases` generated
+/// inputs, stopping at the first disagreement and shrinking that input to a
+/// minimal one. A solver that panics or runs out of time counts as an
+/// answer like any other.
+pub fn check<S: Solution>(config: &Config) -> Result<Agreement, CheckError> {
+    quiet_panics();
+    let mut parts = BTreeSet::new();
+
+    for seed in config.seed..config.seed + config.cases {
+        let generated = S::generate(&mut Rng::new(seed), config.scale.max(1))
+            .ok_or(CheckError::NoGenerator)?;
+        let overrides = config.overrides.iter().map(|(name, value)| (name.as_str(), *value));
+        let params = S::default_params()
+            .with_overrides(generated.params.iter().copied())
+            .and_then(|params| params.with_overrides(S::REFERENCE_PARAMS.iter().copied()))
+            .and_then(|params| params.with_overrides(overrides))
+            .map_err(CheckError::Params)?;
+        let parsed = S::parse(&generated.input)
+            .map_err(|error| CheckError::Parse { seed, error })?;
+
+        for part in [1, 2] {
+            let Some((expected, actual)) = compare::<S>(&parsed, &params, part, config.budget) else { continue };
+            parts.insert(part);
+            if expected == actual { continue }
+
+            let (input, expected, actual) = shrink::<S>(&generated.input, &params, part, config.budget)
+                .unwrap_or((generated.input, expected, actual));
+            return Err(CheckError::Disagreement(Box::new(Counterexample {
+                seed,
+                part,
+                input,
+                params: params.names().map(|name| (name.to_string(), params.get(name))).collect(),
+                expected,
+                actual,
+            })))
+        }
+    }
+
+    Ok(Agreement {
+        cases: config.cases,
+        parts: parts.into_iter().collect(),
+    })
+}
+
+
+/// Test helper that cross-checks a day on a few dozen inputs, failing with
+/// the shrunk counterexample if there is one.
+pub fn assert_agree<S: Solution>() {
+    let config = Config { cases: 30, ..Config::default() };
+    match check::<S>(&config) {
+        Ok(agreement) => assert!(!agreement.parts.is_empty(), "No part has a reference solver"),
+        Err(err) => panic!("Cross-check failed: {err}"),
+    }
+}
+
+
+/// The reference's and the solver's outcomes for one part, or `None` if the
+/// part has no reference.
+fn compare<S: Solution>(parsed: &S::Parsed, params: &Params, part: u8, budget: Duration) -> Option<(Outcome, Outcome)> {
+    let expected = attempt(budget, || match part {
+        1 => S::reference_part1(parsed, params),
+        2 => S::reference_part2(parsed, params),
+        _ => panic!("Invalid part {part}"),
+    })?;
+    let actual = attempt(budget, || match part {
+        1 => Some(S::part1(parsed, params)),
+        _ => Some(S::part2(parsed, params)),
+    })?;
+    Some((expected, actual))
+}
+
+
+/// Runs a solver on its own thread with a time budget, catching panics.
+/// `None` if it had no answer to give.
+fn attempt(budget: Duration, solve: impl FnOnce() -> Option<Answer> + Send) -> Option<Outcome> {
+    let result = cancel::with_budget(budget, || {
+        QUIET.set(true);
+        panic::catch_unwind(AssertUnwindSafe(solve))
+    });
+
+    match result {
+        Some(Ok(answer)) => answer.map(|answer| Outcome::Answer(answer.to_string())),
+        Some(Err(payload)) if !payload.is::<Cancelled>() => Some(Outcome::Panicked(panic_message(&*payload))),
+        _ => Some(Outcome::TimedOut),
+    }
+}
+
+
+/// Greedily replaces `input` with smaller variants for as long as the part
+/// still disagrees with a reference that gives an answer. Returns the
+/// smallest input found and both outcomes on it, or `None` if the
+/// reference had no answer for `input` itself.
+fn shrink<S: Solution>(input: &str, params: &Params, part: u8, budget: Duration) -> Option<(String, Outcome, Outcome)> {
+    let disagreement = |input: &str| {
+        let parsed = S::parse(input).ok()?;
+        let (expected, actual) = compare::<S>(&parsed, params, part, budget)?;
+        (matches!(expected, Outcome::Answer(_)) && expected != actual).then_some((expected, actual))
+    };
+
+    let (mut expected, mut actual) = disagreement(input)?;
+    let mut input = input.to_string();
+    'shrinking: loop {
+        for candidate in candidates(&input) {
+            if let Some(outcomes) = disagreement(&candidate) {
+                (input, (expected, actual)) = (candidate, outcomes);
+                continue 'shrinking
+            }
+        }
+        return Some((input, expected, actual))
+    }
+}
+
+
+/// Smaller variants of `input`, biggest cuts first: without a block of
+/// lines, without a line, without an item of a list on one line, and with
+/// one number made smaller.
+fn candidates(input: &str) -> Vec<String> {
+    let mut candidates = vec![];
+
+    let blocks = input.trim_end().split("\n\n").collect::<Vec<_>>();
+    if blocks.len() > 1 {
+        for idx in 0..blocks.len() {
+            candidates.push(without(&blocks, idx, "\n\n") + "\n");
+        }
+    }
+
+    let lines = input.lines().collect::<Vec<_>>();
+    for idx in 0..lines.len() {
+        candidates.push(without(&lines, idx, "\n") + "\n");
+    }
+
+    for (idx, line) in lines.iter().enumerate() {
+        let Some(separator) = [", ", ",", " "].into_iter().find(|sep| line.contains(sep)) else { continue };
+        let items = line.split(separator).collect::<Vec<_>>();
+        for item in 0..items.len() {
+            let shrunk_line = without(&items, item, separator);
+            let mut shrunk = lines.clone();
+            shrunk[idx] = &shrunk_line;
+            candidates.push(shrunk.join("\n") + "\n");
+        }
+    }
+
+    for number in NUMBER_RE.find_iter(input) {
+        let Ok(value) = number.as_str().parse::<u128>() else { continue };
+        let smaller = [0, value / 2, value.saturating_sub(1)].into_iter()
+            .filter(|&smaller| smaller < value)
+            .collect::<BTreeSet<_>>();
+        for smaller in smaller {
+            candidates.push(format!("{}{smaller}{}", &input[..number.start()], &input[number.end()..]));
+        }
+    }
+
+    candidates
+}
+
+
+fn without(items: &[&str], idx: usize, separator: &str) -> String {
+    items.iter()
+        .enumerate()
+        .filter(|&(other, _)| other != idx)
+        .map(|(_, item)| *item)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+
+/// Installs, once, a panic hook that stays silent on [`QUIET`] threads and
+/// otherwise defers to the one it replaced.
+fn quiet_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generate::Generated;
+
+    /// Sums a line of numbers, but drops any over 50.
+    struct BrokenSum;
+
+    impl Solution for BrokenSum {
+        type Parsed = Vec<u64>;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            input.split_whitespace()
+                .map(|s| crate::parse::number(input, s))
+                .collect()
+        }
+
+        fn part1(input: &Self::Parsed, _params: &Params) -> Answer {
+            input.iter().filter(|&&n| n <= 50).sum::<u64>().into()
+        }
+
+        fn part2(input: &Self::Parsed, _params: &Params) -> Answer {
+            input.len().into()
+        }
+
+        fn generate(rng: &mut Rng, _scale: u32) -> Option<Generated> {
+            let numbers = (0..6).map(|_| rng.range(0..100).to_string()).collect::<Vec<_>>();
+            Some(format!("{}\n", numbers.join(" ")).into())
+        }
+
+        fn reference_part1(input: &Self::Parsed, _params: &Params) -> Option<Answer> {
+            Some(input.iter().sum::<u64>().into())
+        }
+    }
+
+    #[test]
+    fn test_shrink() {
+        let Err(CheckError::Disagreement(counterexample)) = check::<BrokenSum>(&Config::default()) else {
+            panic!("Expected a disagreement")
+        };
+        assert_eq!(counterexample.part, 1);
+        assert_eq!(counterexample.input, "51\n");
+        assert_eq!(counterexample.expected, Outcome::Answer("51".to_string()));
+        assert_eq!(counterexample.actual, Outcome::Answer("0".to_string()));
+    }
+
+    #[test]
+    fn test_candidates() {
+        assert_eq!(candidates("r, wr\n\nbw\n"), [
+            "bw\n", "r, wr\n",
+            "\nbw\n", "r, wr\nbw\n", "r, wr\n\n",
+            "wr\n\nbw\n", "r\n\nbw\n",
+        ]);
+        assert_eq!(candidates("7,10"), ["\n", "10\n", "7\n", "0,10", "3,10", "6,10", "7,0", "7,5", "7,9"]);
+    }
+}
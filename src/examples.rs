@@ -0,0 +1,103 @@
+use std::fmt;
+
+use crate::Solution;
+use crate::input;
+use crate::parse::ParseError;
+
+/// A worked example from the puzzle text: the input, any parameters it needs
+/// and whichever part answers the text gives for it.
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub params: &'static [(&'static str, i64)],
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+/// Declares an [`Example`] read from the file of the same name next to the
+/// calling module.
+#[macro_export]
+macro_rules! example {
+    ($name:literal) => {
+        $crate::examples::Example::new($name, include_str!($name))
+    };
+}
+
+impl Example {
+    pub const fn new(name: &'static str, input: &'static str) -> Self {
+        Self { name, input, params: &[], part1: None, part2: None }
+    }
+
+    pub const fn params(self, params: &'static [(&'static str, i64)]) -> Self {
+        Self { params, ..self }
+    }
+
+    pub const fn part1(self, answer: &'static str) -> Self {
+        Self { part1: Some(answer), ..self }
+    }
+
+    pub const fn part2(self, answer: &'static str) -> Self {
+        Self { part2: Some(answer), ..self }
+    }
+}
+
+/// The outcome of one part of one example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub example: &'static str,
+    pub part: u8,
+    pub expected: &'static str,
+    pub answer: Result<String, ParseError>,
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        self.answer.as_deref() == Ok(self.expected)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.answer {
+            Ok(answer) if self.passed() => write!(f, "{} part {}: pass - got {answer}", self.example, self.part),
+            Ok(answer) => write!(f, "{} part {}: FAIL (expected {}) - got {answer}", self.example, self.part, self.expected),
+            Err(err) => write!(f, "{} part {}: FAIL (expected {}) - {err}", self.example, self.part, self.expected),
+        }
+    }
+}
+
+
+/// Runs every part with a known answer in each of the day's examples, after
+/// the same normalization as real puzzle input.
+pub fn run<S: Solution>() -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+
+    for example in S::EXAMPLES {
+        let params = S::default_params()
+            .with_overrides(example.params.iter().copied())
+            .unwrap_or_else(|err| panic!("Example {}: {err}", example.name));
+        let parsed = S::parse(&input::normalize(example.input));
+
+        if let Some(expected) = example.part1 {
+            let answer = parsed.as_ref().map(|parsed| S::part1(parsed, &params).to_string()).map_err(Clone::clone);
+            outcomes.push(Outcome { example: example.name, part: 1, expected, answer });
+        }
+        if let Some(expected) = example.part2 {
+            let answer = parsed.as_ref().map(|parsed| S::part2(parsed, &params).to_string()).map_err(Clone::clone);
+            outcomes.push(Outcome { example: example.name, part: 2, expected, answer });
+        }
+    }
+
+    outcomes
+}
+
+
+/// Test helper that fails with every mismatching example answer.
+pub fn assert_pass<S: Solution>() {
+    let failures = run::<S>().iter()
+        .filter(|outcome| !outcome.passed())
+        .map(|outcome| outcome.to_string())
+        .collect::<Vec<_>>();
+    assert!(failures.is_empty(), "Examples failed:\n{}", failures.join("\n"));
+}
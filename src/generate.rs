@@ -0,0 +1,162 @@
+use std::ops::Range;
+
+use crate::grid::Grid;
+
+/// A small seeded random number generator (SplitMix64). Kept in the crate
+/// rather than pulled in as a dependency so a seed always produces the same
+/// input, whatever version of a library happens to be locked.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A uniform value below `n`. Panics if `n` is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// A uniform value in `range`. Panics if it is empty.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "Empty range {range:?}");
+        let span = range.end.abs_diff(range.start);
+        range.start.wrapping_add(self.below(span) as i64)
+    }
+
+    /// A uniform index into a collection of `len` items.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability one in `n`.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.index(idx + 1));
+        }
+    }
+}
+
+
+/// A generated puzzle input, along with any parameters it must be solved
+/// with in place of the day's defaults, such as a smaller grid size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub params: Vec<(&'static str, i64)>,
+}
+
+impl Generated {
+    pub fn with_params(mut self, params: &[(&'static str, i64)]) -> Self {
+        self.params.extend_from_slice(params);
+        self
+    }
+}
+
+impl From<String> for Generated {
+    fn from(input: String) -> Self {
+        Self { input, params: vec![] }
+    }
+}
+
+
+/// A random perfect maze of `rows` by `cols` rooms, as a grid of walls
+/// (`true`) two cells bigger than twice that in each direction. Rooms sit at
+/// odd coordinates and any two are joined by exactly one path.
+pub fn maze(rng: &mut Rng, rows: usize, cols: usize) -> Grid<bool> {
+    let mut walls = Grid::filled(2 * rows + 1, 2 * cols + 1, true);
+    let mut stack = vec![(1, 1)];
+    walls[(1, 1)] = false;
+
+    while let Some(&(row, col)) = stack.last() {
+        let unvisited = [(-2, 0), (2, 0), (0, -2), (0, 2)].into_iter()
+            .filter_map(|delta| walls.offset((row, col), delta))
+            .filter(|&next| walls[next])
+            .collect::<Vec<_>>();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue
+        }
+
+        let next = *rng.pick(&unvisited);
+        walls[((row + next.0) / 2, (col + next.1) / 2)] = false;
+        walls[next] = false;
+        stack.push(next);
+    }
+
+    walls
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let draws = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..8).map(|_| rng.range(-3..4)).collect::<Vec<_>>()
+        };
+        assert_eq!(draws(7), draws(7));
+        assert_ne!(draws(7), draws(8));
+        assert!(draws(7).iter().all(|val| (-3..4).contains(val)));
+
+        let mut items = (0..10).collect::<Vec<_>>();
+        Rng::new(1).shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_maze() {
+        let walls = maze(&mut Rng::new(3), 4, 6);
+        assert_eq!(walls.dim(), (9, 13));
+
+        let open = walls.positions(|&wall| !wall).collect::<Vec<_>>();
+        let reached = crate::search::bfs((1, 1), |&pos| {
+            walls.neighbors4(pos).filter(|&next| !walls[next]).collect::<Vec<_>>()
+        });
+        assert_eq!(reached.reached().count(), open.len());
+        // A tree over the 24 rooms has 23 passages between them
+        assert_eq!(open.len(), 24 + 23);
+    }
+
+    #[test]
+    fn test_days() {
+        for day in crate::DAYS.iter() {
+            assert_eq!(day.generate(5, 2), day.generate(5, 2), "day {}", day.day);
+
+            for seed in 0..4 {
+                let generated = day.generate(seed, 1)
+                    .unwrap_or_else(|| panic!("day {} has no generator", day.day));
+                let params = day.default_params()
+                    .with_overrides(generated.params.iter().copied())
+                    .unwrap();
+                if let Err(err) = day.run(&generated.input, &params) {
+                    panic!("day {} seed {seed}: {err}\n{}", day.day, generated.input);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::grid::Idx;
+
+/// A 2D integer point or offset. `x` grows to the right and `y` grows
+/// downwards, matching how puzzle maps are laid out in the input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// The four compass directions, with north pointing up the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Point {
+    pub const ZERO: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Wraps the point into `0..size.x` by `0..size.y`, as on a torus.
+    pub fn rem_euclid(self, size: Point) -> Self {
+        Self {
+            x: self.x.rem_euclid(size.x),
+            y: self.y.rem_euclid(size.y),
+        }
+    }
+
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Dir::ALL.into_iter().map(move |dir| self + dir.delta())
+    }
+
+    /// The grid index of this point, if neither coordinate is negative.
+    pub fn to_idx(self) -> Option<Idx> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+
+    pub fn from_idx((row, col): Idx) -> Self {
+        Self::new(col as i64, row as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, scalar: i64) -> Point {
+        Point::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Dir {
+    /// Clockwise from north.
+    pub const ALL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Dir::North => Dir::West,
+            Dir::East => Dir::North,
+            Dir::South => Dir::East,
+            Dir::West => Dir::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Dir::North => Dir::East,
+            Dir::East => Dir::South,
+            Dir::South => Dir::West,
+            Dir::West => Dir::North,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Dir::North => Dir::South,
+            Dir::East => Dir::West,
+            Dir::South => Dir::North,
+            Dir::West => Dir::East,
+        }
+    }
+
+    /// The unit step in this direction.
+    pub fn delta(self) -> Point {
+        match self {
+            Dir::North => Point::new(0, -1),
+            Dir::East => Point::new(1, 0),
+            Dir::South => Point::new(0, 1),
+            Dir::West => Point::new(-1, 0),
+        }
+    }
+
+    /// Parses the `^`, `>`, `v` and `<` arrows used in puzzle inputs.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Dir::North),
+            '>' => Some(Dir::East),
+            'v' => Some(Dir::South),
+            '<' => Some(Dir::West),
+            _ => None,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_point_ops() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 5);
+        assert_eq!(a + b, Point::new(2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(a * 3, Point::new(9, -6));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(Point::new(-1, 12).rem_euclid(Point::new(11, 7)), Point::new(10, 5));
+    }
+
+    #[test]
+    fn test_idx() {
+        assert_eq!(Point::new(2, 5).to_idx(), Some((5, 2)));
+        assert_eq!(Point::new(-1, 5).to_idx(), None);
+        assert_eq!(Point::from_idx((5, 2)), Point::new(2, 5));
+    }
+
+    #[test]
+    fn test_dir() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.delta() + dir.opposite().delta(), Point::ZERO);
+        }
+        assert_eq!(Dir::North.turn_right(), Dir::East);
+        assert_eq!(Dir::from_arrow('<'), Some(Dir::West));
+        assert_eq!(Point::ZERO.neighbors4().count(), 4);
+    }
+}
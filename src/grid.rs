@@ -0,0 +1,269 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use ndarray::Array2;
+
+use crate::geom::Point;
+use crate::parse::ParseError;
+
+/// A `(row, col)` index into a [`Grid`].
+pub type Idx = (usize, usize);
+
+/// Up, down, left and right as `(row, col)` deltas.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// The orthogonal deltas followed by the four diagonals.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, 0), (1, 0), (0, -1), (0, 1),
+    (-1, -1), (-1, 1), (1, -1), (1, 1),
+];
+
+/// A rectangular grid of cells indexed by `(row, col)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    data: Array2<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(nrows: usize, ncols: usize, data: Vec<T>) -> Self {
+        Self {
+            data: Array2::from_shape_vec((nrows, ncols), data).unwrap()
+        }
+    }
+
+    pub fn filled(nrows: usize, ncols: usize, val: T) -> Self
+    where
+        T: Clone
+    {
+        Self {
+            data: Array2::from_elem((nrows, ncols), val)
+        }
+    }
+
+    /// Builds a grid from one line of text per row, mapping each char to a
+    /// cell. Fails on chars `f` rejects or rows of differing lengths.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut data = Vec::new();
+        let mut ncols = None;
+        let mut nrows = 0;
+
+        for line in input.lines() {
+            let mut len = 0;
+            for (idx, c) in line.char_indices() {
+                let cell = f(c)
+                    .ok_or_else(|| ParseError::at(input, &line[idx..], format!("unexpected character {c:?}")))?;
+                data.push(cell);
+                len += 1;
+            }
+
+            let ncols = *ncols.get_or_insert(len);
+            if len != ncols {
+                return Err(ParseError::at(input, line, format!("expected a row of {ncols} cells, found {len}")))
+            }
+            nrows += 1;
+        }
+
+        Ok(Self::from_vec(nrows, ncols.unwrap_or(0), data))
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.data.nrows()
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.data.ncols()
+    }
+
+    pub fn dim(&self) -> (usize, usize) {
+        self.data.dim()
+    }
+
+    pub fn contains(&self, pos: Idx) -> bool {
+        pos.0 < self.nrows() && pos.1 < self.ncols()
+    }
+
+    pub fn get(&self, pos: Idx) -> Option<&T> {
+        self.data.get(pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Idx) -> Option<&mut T> {
+        self.data.get_mut(pos)
+    }
+
+    /// Looks up the cell at `point.y` row, `point.x` column.
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.to_idx()?)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.to_idx()?)
+    }
+
+    /// Looks up a cell from signed coordinates, which may fall off any edge.
+    pub fn get_signed(&self, row: isize, col: isize) -> Option<&T> {
+        let row = usize::try_from(row).ok()?;
+        let col = usize::try_from(col).ok()?;
+        self.get((row, col))
+    }
+
+    /// Moves `pos` by a signed `(row, col)` delta, if it stays on the grid.
+    pub fn offset(&self, pos: Idx, delta: (isize, isize)) -> Option<Idx> {
+        let row = pos.0.checked_add_signed(delta.0)?;
+        let col = pos.1.checked_add_signed(delta.1)?;
+        let new_pos = (row, col);
+        self.contains(new_pos).then_some(new_pos)
+    }
+
+    pub fn get_offset(&self, pos: Idx, delta: (isize, isize)) -> Option<&T> {
+        self.get(self.offset(pos, delta)?)
+    }
+
+    /// The up to four orthogonal neighbours of `pos` that are on the grid.
+    pub fn neighbors4(&self, pos: Idx) -> impl Iterator<Item = Idx> + '_ {
+        ORTHOGONAL.into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// The up to eight orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbors8(&self, pos: Idx) -> impl Iterator<Item = Idx> + '_ {
+        ALL_DIRECTIONS.into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    /// Every cell with its position, in row-major order.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Idx, &T)> {
+        self.data.indexed_iter()
+    }
+
+    pub fn positions<'a>(&'a self, mut pred: impl FnMut(&T) -> bool + 'a) -> impl Iterator<Item = Idx> + 'a {
+        self.indexed_iter()
+            .filter_map(move |(pos, val)| pred(val).then_some(pos))
+    }
+
+    /// The first position, in row-major order, holding `val`.
+    pub fn find(&self, val: &T) -> Option<Idx>
+    where
+        T: PartialEq
+    {
+        self.positions(|v| v == val).next()
+    }
+
+    /// The position of the one cell equal to `val`, for grids parsed from
+    /// `input`. Errors name the cell as `what` and point into `input`.
+    pub fn find_unique(&self, input: &str, val: &T, what: &str) -> Result<Idx, ParseError>
+    where
+        T: PartialEq
+    {
+        let mut found = self.positions(|v| v == val);
+        let pos = found.next()
+            .ok_or_else(|| ParseError::at_end(input, format!("no {what} on the map")))?;
+
+        if let Some((row, col)) = found.next() {
+            let line = input.lines().nth(row).unwrap_or_default();
+            let rest = line.char_indices().nth(col).map_or(line, |(idx, _)| &line[idx..]);
+            return Err(ParseError::at(input, rest, format!("more than one {what} on the map")))
+        }
+        Ok(pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            data: self.data.map(f)
+        }
+    }
+
+    /// Draws the grid one char per cell, with a newline after each row.
+    pub fn render(&self, mut f: impl FnMut(Idx, &T) -> char) -> String {
+        let mut out = String::with_capacity(self.nrows() * (self.ncols() + 1));
+        for (pos, val) in self.indexed_iter() {
+            out.push(f(pos, val));
+            if pos.1 == self.ncols() - 1 {
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+impl<T> Index<Idx> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Idx) -> &T {
+        &self.data[pos]
+    }
+}
+
+impl<T> IndexMut<Idx> for Grid<T> {
+    fn index_mut(&mut self, pos: Idx) -> &mut T {
+        &mut self.data[pos]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.data.rows() {
+            for val in row {
+                write!(f, "{val}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EX: &str = "ab.\n.#c\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(EX, Some).unwrap();
+        assert_eq!(grid.dim(), (2, 3));
+        assert_eq!(grid[(1, 2)], 'c');
+        assert_eq!(grid.find(&'#'), Some((1, 1)));
+        assert_eq!(grid.positions(|&c| c == '.').collect::<Vec<_>>(), vec![(0, 2), (1, 0)]);
+        assert_eq!(grid.to_string(), EX);
+    }
+
+    #[test]
+    fn test_signed_access() {
+        let grid = Grid::parse(EX, Some).unwrap();
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(0, 3), None);
+        assert_eq!(grid.get_signed(1, 0), Some(&'.'));
+        assert_eq!(grid.offset((0, 0), (1, 2)), Some((1, 2)));
+        assert_eq!(grid.offset((0, 0), (0, -1)), None);
+        assert_eq!(grid.get_offset((1, 1), (-1, -1)), Some(&'a'));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse("ab\nc\n", Some).unwrap_err();
+        assert_eq!((err.line, err.col), (2, 1));
+
+        let err = Grid::parse("ab\ncd\n", |c| (c != 'd').then_some(c)).unwrap_err();
+        assert_eq!((err.line, err.col), (2, 2));
+
+        let input = "a.\n.a\n";
+        let grid = Grid::parse(input, Some).unwrap();
+        let err = grid.find_unique(input, &'a', "a").unwrap_err();
+        assert_eq!((err.line, err.col), (2, 2));
+        assert!(grid.find_unique(input, &'b', "b").is_err());
+    }
+}
@@ -0,0 +1,129 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering::Relaxed};
+
+use serde::{Deserialize, Serialize};
+
+/// Whether [`Counting`] is the global allocator, which takes building with
+/// the `count-allocs` feature. Without it [`measure`] has nothing to report.
+pub const INSTALLED: bool = cfg!(feature = "count-allocs");
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Heap use over one call to [`measure`], across all threads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeapStats {
+    /// Allocations made, counting each reallocation that grew a block.
+    pub allocations: u64,
+    /// Bytes handed out, counting only the growth of a reallocated block.
+    pub allocated_bytes: u64,
+    /// The most that was live at once, beyond what was live beforehand.
+    pub peak_bytes: u64,
+}
+
+/// Runs `f`, counting the heap it uses if [`Counting`] is installed.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<HeapStats>) {
+    if !INSTALLED {
+        return (f(), None)
+    }
+
+    let allocations = ALLOCATIONS.load(Relaxed);
+    let allocated = ALLOCATED.load(Relaxed);
+    let live = LIVE.load(Relaxed);
+    PEAK.store(live, Relaxed);
+
+    let result = f();
+    let stats = HeapStats {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        allocated_bytes: ALLOCATED.load(Relaxed) - allocated,
+        peak_bytes: PEAK.load(Relaxed).saturating_sub(live) as u64,
+    };
+    (result, Some(stats))
+}
+
+
+/// A global allocator that hands everything to [`System`], keeping count of
+/// allocations and of the bytes live.
+pub struct Counting;
+
+impl Counting {
+    fn grew(bytes: usize) {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        ALLOCATED.fetch_add(bytes as u64, Relaxed);
+        let live = LIVE.fetch_add(bytes, Relaxed) + bytes;
+        PEAK.fetch_max(live, Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            match new_size.checked_sub(layout.size()) {
+                Some(growth) => Self::grew(growth),
+                None => { LIVE.fetch_sub(layout.size() - new_size, Relaxed); },
+            }
+        }
+        new_ptr
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_counting() {
+        let before = (ALLOCATIONS.load(Relaxed), ALLOCATED.load(Relaxed));
+        unsafe {
+            let layout = Layout::from_size_align(64, 8).unwrap();
+            let ptr = Counting.alloc(layout);
+            let ptr = Counting.realloc(ptr, layout, 256);
+            let ptr = Counting.realloc(ptr, Layout::from_size_align(256, 8).unwrap(), 32);
+            Counting.dealloc(ptr, Layout::from_size_align(32, 8).unwrap());
+        }
+        // Other tests may allocate too if the allocator is installed
+        assert!(ALLOCATIONS.load(Relaxed) - before.0 >= 2);
+        assert!(ALLOCATED.load(Relaxed) - before.1 >= 256);
+    }
+
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| vec![0u8; 1 << 20].len());
+        assert_eq!(len, 1 << 20);
+        assert_eq!(stats.is_some(), INSTALLED);
+        if let Some(stats) = stats {
+            assert!(stats.allocations >= 1);
+            assert!(stats.allocated_bytes >= 1 << 20);
+            assert!(stats.peak_bytes >= 1 << 20);
+        }
+    }
+}
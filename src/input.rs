@@ -0,0 +1,118 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Directory searched for puzzle inputs when no explicit path is given.
+pub const INPUT_DIR: &str = "inputs";
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone)]
+pub enum InputSource {
+    /// `inputs/day_N.txt`, relative to the working directory.
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command line argument, with `-` meaning stdin.
+    pub fn from_arg(arg: Option<PathBuf>) -> Self {
+        match arg {
+            None => Self::Default,
+            Some(path) if path.as_os_str() == "-" => Self::Stdin,
+            Some(path) => Self::Path(path),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    day: u32,
+    path: Option<PathBuf>,
+    source: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => {
+                write!(f, "couldn't read input for day {} from {}: {}", self.day, path.display(), self.source)?;
+                if self.source.kind() == io::ErrorKind::NotFound && *path == default_path(self.day) {
+                    write!(f, " (save your puzzle input there or pass --input <PATH>)")?;
+                }
+                Ok(())
+            },
+            None => write!(f, "couldn't read input for day {} from stdin: {}", self.day, self.source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+
+pub fn default_path(day: u32) -> PathBuf {
+    Path::new(INPUT_DIR).join(format!("day_{day}.txt"))
+}
+
+
+/// Reads a day's puzzle input and [normalizes](normalize) it.
+pub fn load(day: u32, source: &InputSource) -> Result<String, InputError> {
+    let input = match source {
+        InputSource::Default => read_file(day, &default_path(day))?,
+        InputSource::Path(path) => read_file(day, path)?,
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)
+                .map_err(|source| InputError { day, path: None, source })?;
+            input
+        },
+    };
+    Ok(normalize(&input))
+}
+
+
+/// Cleans up input saved or pasted by other tools so parsers only ever see
+/// plain LF text: drops a leading byte order mark, converts CRLF line endings,
+/// strips trailing whitespace from every line and ends the text with exactly
+/// one newline. Grids are checked for ragged rows by [`Grid::parse`].
+///
+/// [`Grid::parse`]: crate::grid::Grid::parse
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+    let mut normalized = String::with_capacity(input.len());
+    for line in input.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+
+    normalized.truncate(normalized.trim_end().len());
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+
+fn read_file(day: u32, path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path)
+        .map_err(|source| InputError { day, path: Some(path.to_path_buf()), source })
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("\u{feff}ab \r\ncd\t\r\n\r\n\r\n"), "ab\ncd\n");
+        assert_eq!(normalize("1|2\n   \n3,4"), "1|2\n\n3,4\n");
+        assert_eq!(normalize("already clean\n"), "already clean\n");
+        assert_eq!(normalize(" \n\n"), "");
+    }
+}
@@ -0,0 +1,78 @@
+pub mod solution;
+pub mod answer;
+pub mod params;
+pub mod parse;
+pub mod examples;
+pub mod runner;
+pub mod input;
+pub mod log;
+pub mod report;
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod generate;
+pub mod differential;
+pub mod complexity;
+pub mod heap;
+pub mod grid;
+pub mod geom;
+pub mod render;
+pub mod search;
+pub mod scaffold;
+pub mod config;
+pub mod client;
+pub mod submit;
+pub mod watch;
+
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+
+pub use solution::Solution;
+use runner::Day;
+
+/// Every implemented day, in order. New days only need adding here.
+pub const DAYS: &[Day] = &[
+    Day::new::<day_1::Day1>(1),
+    Day::new::<day_2::Day2>(2),
+    Day::new::<day_3::Day3>(3),
+    Day::new::<day_4::Day4>(4),
+    Day::new::<day_5::Day5>(5),
+    Day::new::<day_6::Day6>(6),
+    Day::new::<day_7::Day7>(7),
+    Day::new::<day_8::Day8>(8),
+    Day::new::<day_9::Day9>(9),
+    Day::new::<day_10::Day10>(10),
+    Day::new::<day_11::Day11>(11),
+    Day::new::<day_12::Day12>(12),
+    Day::new::<day_13::Day13>(13),
+    Day::new::<day_14::Day14>(14),
+    Day::new::<day_15::Day15>(15),
+    Day::new::<day_16::Day16>(16),
+    Day::new::<day_17::Day17>(17),
+    Day::new::<day_18::Day18>(18),
+    Day::new::<day_19::Day19>(19),
+    Day::new::<day_20::Day20>(20),
+];
+
+
+pub fn get_day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
@@ -0,0 +1,170 @@
+use std::cell::Cell;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+/// How much detail a diagnostic carries, from least to most verbose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            other => Err(format!("unknown log level `{other}`")),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Which diagnostics to print: a level for everything, optionally overridden
+/// per day. Nothing is printed by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    pub default: Option<Level>,
+    pub days: Vec<(u32, Level)>,
+}
+
+impl Filter {
+    /// Parses a comma separated list of `LEVEL` and `dayN=LEVEL` entries,
+    /// e.g. `warn,day17=trace`.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut filter = Filter::default();
+        for entry in s.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            match entry.split_once('=') {
+                Some((day, level)) => {
+                    let day = day.trim().strip_prefix("day")
+                        .and_then(|day| day.parse().ok())
+                        .ok_or_else(|| format!("expected `dayN=LEVEL`, found `{entry}`"))?;
+                    filter.days.push((day, level.parse()?));
+                },
+                None => filter.default = Some(entry.parse()?),
+            }
+        }
+        Ok(filter)
+    }
+
+    /// The most verbose level shown for `day`, or for code running outside
+    /// any day.
+    pub fn max_level(&self, day: Option<u32>) -> Option<Level> {
+        day.and_then(|day| self.days.iter().rev().find(|(d, _)| *d == day))
+            .map(|&(_, level)| level)
+            .or(self.default)
+    }
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter { default: None, days: Vec::new() });
+
+thread_local! {
+    static CURRENT_DAY: Cell<Option<u32>> = const { Cell::new(None) };
+}
+
+
+pub fn set_filter(filter: Filter) {
+    *FILTER.write().unwrap() = filter;
+}
+
+
+/// Runs `f` with diagnostics attributed to `day`.
+pub fn with_day<T>(day: u32, f: impl FnOnce() -> T) -> T {
+    let outer = CURRENT_DAY.replace(Some(day));
+    let result = f();
+    CURRENT_DAY.set(outer);
+    result
+}
+
+
+pub fn enabled(level: Level) -> bool {
+    let filter = FILTER.read().unwrap();
+    filter.max_level(CURRENT_DAY.get()).is_some_and(|max| level <= max)
+}
+
+
+/// Prints a diagnostic to stderr. Use the level macros, which skip
+/// formatting entirely when the level is filtered out.
+pub fn write(level: Level, args: fmt::Arguments) {
+    match CURRENT_DAY.get() {
+        Some(day) => eprintln!("[{level} day {day}] {args}"),
+        None => eprintln!("[{level}] {args}"),
+    }
+}
+
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter = Filter::parse("warn,day17=trace").unwrap();
+        assert_eq!(filter.max_level(None), Some(Level::Warn));
+        assert_eq!(filter.max_level(Some(3)), Some(Level::Warn));
+        assert_eq!(filter.max_level(Some(17)), Some(Level::Trace));
+
+        let filter = Filter::parse("day18=debug").unwrap();
+        assert_eq!(filter.max_level(Some(17)), None);
+        assert_eq!(filter.max_level(Some(18)), Some(Level::Debug));
+
+        assert!(Filter::parse("loud").is_err());
+        assert!(Filter::parse("17=debug").is_err());
+    }
+}
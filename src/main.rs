@@ -0,0 +1,841 @@
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+
+use aoc2024::{get_day, DAYS};
+use aoc2024::answers::{self, Verdict};
+use aoc2024::client::{self, Client, Fetched};
+use aoc2024::complexity::{self, ComplexityConfig};
+use aoc2024::config::{self, Config};
+use aoc2024::differential;
+use aoc2024::bench::{self, BenchConfig};
+use aoc2024::input::{self, InputSource};
+use aoc2024::log;
+use aoc2024::params::{self, Params};
+use aoc2024::render;
+use aoc2024::report::{format_ns, Format, Report};
+use aoc2024::runner::Day;
+use aoc2024::scaffold;
+use aoc2024::submit::{self, Outcome};
+use aoc2024::watch::{self, Snapshot, Stamps};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Print solver diagnostics to stderr, e.g. `debug` or `warn,day17=trace`.
+    /// Also read from `AOC_LOG`
+    #[arg(long, global = true, value_name = "FILTER", value_parser = log::Filter::parse)]
+    log: Option<log::Filter>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one or more days against their puzzle input
+    Run {
+        #[command(flatten)]
+        days: DayArgs,
+
+        /// How to print the answers and timings. Builds with the
+        /// `count-allocs` feature also show heap allocations for each step
+        #[arg(short, long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+
+        /// Stop any part still running after this many seconds
+        #[arg(short, long, value_name = "SECONDS", value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+    },
+    /// Check each day's answers against those recorded in `answers/day_N.txt`
+    Verify {
+        #[command(flatten)]
+        days: DayArgs,
+
+        /// Save the current answer for any part that has none recorded yet
+        #[arg(long)]
+        record: bool,
+
+        /// Stop any part still running after this many seconds
+        #[arg(short, long, value_name = "SECONDS", value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+    },
+    /// Time each day's parse and parts over repeated runs
+    Bench {
+        #[command(flatten)]
+        days: DayArgs,
+
+        /// Number of timed runs per step
+        #[arg(short, long, default_value_t = 10)]
+        samples: u32,
+
+        /// Number of untimed runs per step before sampling
+        #[arg(short, long, default_value_t = 1)]
+        warmup: u32,
+
+        /// Compare medians against a baseline saved with `--save`
+        #[arg(short, long, value_name = "PATH")]
+        baseline: Option<PathBuf>,
+
+        /// Percentage slowdown against the baseline counted as a regression
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Save these results as a baseline for later comparison
+        #[arg(long, value_name = "PATH")]
+        save: Option<PathBuf>,
+    },
+    /// Create a new day's module from the `src/day_x` template and register it
+    New {
+        /// The day to create
+        day: u32,
+    },
+    /// Download puzzle inputs into `inputs/`, skipping any already there
+    Fetch {
+        /// Day or range of days to fetch, e.g. `16` or `1..=20`
+        #[arg(value_parser = parse_days)]
+        days: RangeInclusive<u32>,
+    },
+    /// Solve one part and submit the answer, unless earlier attempts rule it out
+    Submit {
+        /// The day to submit
+        day: u32,
+
+        /// The part to submit
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Read the puzzle input from this file, or `-` for stdin, instead of
+        /// `inputs/day_N.txt`
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<PathBuf>,
+
+        /// Override a puzzle parameter, after any set in `aoc.toml`
+        #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = params::parse_assignment)]
+        params: Vec<(String, i64)>,
+
+        /// Submit this answer instead of solving the part
+        #[arg(short, long)]
+        answer: Option<String>,
+    },
+    /// Re-run a day's examples and input whenever its module, examples or
+    /// input change, showing which answers moved
+    Watch {
+        /// The day to watch
+        day: u32,
+
+        /// Read the puzzle input from this file instead of `inputs/day_N.txt`
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<PathBuf>,
+
+        /// Override a puzzle parameter, after any set in `aoc.toml`
+        #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = params::parse_assignment)]
+        params: Vec<(String, i64)>,
+
+        /// Build the solutions with optimizations
+        #[arg(long)]
+        release: bool,
+
+        /// Run once and print the answers as JSON for the watching process
+        #[arg(long, hide = true)]
+        once: bool,
+    },
+    /// Solve one part while recording the frames it draws, then play them in
+    /// the terminal or save them as images
+    Render {
+        /// The day to render
+        day: u32,
+
+        /// The part to render
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Read the puzzle input from this file, or `-` for stdin, instead of
+        /// `inputs/day_N.txt`
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<PathBuf>,
+
+        /// Override a puzzle parameter, after any set in `aoc.toml`
+        #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = params::parse_assignment)]
+        params: Vec<(String, i64)>,
+
+        /// Save to a `.gif`, `.png`, `.ppm` or `.txt` file instead of playing
+        /// in the terminal
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+
+        /// Keep only every Nth frame
+        #[arg(short, long, default_value_t = 1)]
+        every: usize,
+
+        /// Pixels per cell in images
+        #[arg(short, long, default_value_t = 4)]
+        scale: usize,
+
+        /// Milliseconds each frame is shown for
+        #[arg(short, long, default_value_t = 100)]
+        delay: u32,
+    },
+    /// Print a random puzzle input for a day, the same every time for a
+    /// given seed and scale
+    Generate {
+        /// The day to generate an input for
+        day: u32,
+
+        /// Seed for the random generator
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How big an input to make, where 1 is about the size of the example
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        scale: u32,
+    },
+    /// Check a day's parts against its slow reference solvers on generated
+    /// inputs, shrinking any disagreement to a small failing input
+    Crosscheck {
+        /// The day to check
+        day: u32,
+
+        /// How many inputs to try
+        #[arg(long, default_value_t = 100)]
+        cases: u64,
+
+        /// Seed for the first input, with each following input using the next
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How big the inputs are, where 1 is about the size of the example
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        scale: u32,
+
+        /// Override a puzzle parameter, after the day's reference parameters
+        #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = params::parse_assignment)]
+        params: Vec<(String, i64)>,
+
+        /// Give up on a solver after this many seconds on one input
+        #[arg(short, long, value_name = "SECONDS", value_parser = parse_timeout, default_value = "5")]
+        timeout: Duration,
+    },
+    /// Time the parse and each part on generated inputs of doubling size and
+    /// fit how fast their time and memory grow
+    Complexity {
+        /// The day to profile
+        day: u32,
+
+        /// The largest scale to try, doubling from 1
+        #[arg(long, default_value_t = 64)]
+        max_scale: u32,
+
+        /// Stop growing a step's input once one run takes longer than this
+        /// many seconds
+        #[arg(short, long, value_name = "SECONDS", value_parser = parse_timeout, default_value = "1")]
+        limit: Duration,
+
+        /// Stop any run still going after this many seconds
+        #[arg(short, long, value_name = "SECONDS", value_parser = parse_timeout, default_value = "10")]
+        timeout: Duration,
+
+        /// Timed runs per size, keeping the fastest
+        #[arg(short, long, default_value_t = 3)]
+        samples: u32,
+
+        /// Seed for the generated inputs
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Override a puzzle parameter, after any the generated input needs
+        #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = params::parse_assignment)]
+        params: Vec<(String, i64)>,
+
+        /// Fail if any step's time grows faster than the input size to this
+        /// power
+        #[arg(long, value_name = "EXPONENT")]
+        max_exponent: Option<f64>,
+    },
+}
+
+#[derive(Args)]
+struct DayArgs {
+    /// Day or range of days to run, e.g. `16`, `1..=20` or `3..7`
+    #[arg(value_parser = parse_days, required_unless_present = "all", conflicts_with = "all")]
+    days: Option<RangeInclusive<u32>>,
+
+    /// Run every implemented day
+    #[arg(long)]
+    all: bool,
+
+    /// Read the puzzle input from this file, or `-` for stdin, instead of
+    /// `inputs/day_N.txt`. Only valid when running a single day.
+    #[arg(short, long, value_name = "PATH")]
+    input: Option<PathBuf>,
+
+    /// Override a puzzle parameter for every selected day that takes it,
+    /// after any set in `aoc.toml`
+    #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = params::parse_assignment)]
+    params: Vec<(String, i64)>,
+}
+
+/// Days to run paired with their puzzle input and parameters.
+type LoadedDays = Vec<(&'static Day, String, Params)>;
+
+impl DayArgs {
+    /// Resolves the requested days and loads each one's input. Days whose
+    /// input can't be read are reported on stderr and counted as failures.
+    fn load(&self) -> Result<(LoadedDays, bool), String> {
+        let days = if self.all { 1..=u32::MAX } else { self.days.clone().unwrap() };
+        let selected = DAYS.iter()
+            .filter(|d| days.contains(&d.day))
+            .collect::<Vec<_>>();
+
+        if selected.is_empty() {
+            return Err(format!("No implemented days in {}..={}", days.start(), days.end()))
+        }
+        if self.input.is_some() && selected.len() > 1 {
+            return Err("--input can only be used when running a single day".to_string())
+        }
+        if let Some((name, _)) = self.params.iter().find(|(name, _)| {
+            !selected.iter().any(|day| day.params.iter().any(|(declared, _)| declared == name))
+        }) {
+            return Err(format!("No selected day takes a parameter `{name}`"))
+        }
+        let config = config::load().map_err(|err| err.to_string())?;
+
+        let source = InputSource::from_arg(self.input.clone());
+        let mut failed = false;
+        let mut loaded = Vec::new();
+        for day in selected {
+            let params = match day_params(day, &config, &self.params) {
+                Ok(params) => params,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    failed = true;
+                    continue
+                },
+            };
+            match input::load(day.day, &source) {
+                Ok(input) => loaded.push((day, input, params)),
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    failed = true;
+                },
+            }
+        }
+        Ok((loaded, failed))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let filter = match cli.log {
+        Some(filter) => filter,
+        None => match std::env::var("AOC_LOG").map(|spec| log::Filter::parse(&spec)) {
+            Ok(Ok(filter)) => filter,
+            Ok(Err(err)) => {
+                eprintln!("error: AOC_LOG: {err}");
+                return ExitCode::FAILURE
+            },
+            Err(_) => log::Filter::default(),
+        },
+    };
+    log::set_filter(filter);
+
+    match cli.command {
+        Command::Run { days, format, timeout } => {
+            let (loaded, mut failed) = match days.load() {
+                Ok(loaded) => loaded,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE
+                },
+            };
+
+            let mut report = Report { days: Vec::new() };
+            for (day, input, params) in loaded.iter() {
+                match day.run_with_budget(input, params, timeout) {
+                    Ok(day_report) => {
+                        failed |= day_report.parts.iter().any(|part| part.timed_out);
+                        report.days.push(day_report);
+                    },
+                    Err(err) => {
+                        eprintln!("error: {err}");
+                        failed = true;
+                    },
+                }
+            }
+            print!("{}", report.render(format));
+
+            if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+        },
+        Command::Verify { days, record, timeout } => {
+            let (loaded, mut failed) = match days.load() {
+                Ok(loaded) => loaded,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE
+                },
+            };
+
+            let (mut passed, mut mismatched, mut missing) = (0, 0, 0);
+            for (day, input, params) in loaded {
+                let mut known = match answers::load(day.day) {
+                    Ok(known) => known,
+                    Err(err) => {
+                        eprintln!("error: {err}");
+                        failed = true;
+                        continue
+                    },
+                };
+
+                let day_report = match day.run_with_budget(&input, &params, timeout) {
+                    Ok(day_report) => day_report,
+                    Err(err) => {
+                        eprintln!("error: {err}");
+                        failed = true;
+                        continue
+                    },
+                };
+                let mut updated = false;
+                for part in day_report.parts.iter() {
+                    if part.timed_out {
+                        println!("Day {:>2} part {}: FAIL - timed out", day.day, part.part);
+                        mismatched += 1;
+                        continue
+                    }
+                    let verdict = known.check(part.part, &part.answer);
+                    match verdict {
+                        Verdict::Pass => passed += 1,
+                        Verdict::Fail { .. } => mismatched += 1,
+                        Verdict::Missing => missing += 1,
+                    }
+                    println!("Day {:>2} part {}: {verdict} - got {}", day.day, part.part, part.answer);
+
+                    if record && verdict == Verdict::Missing {
+                        known.set(part.part, part.answer.clone());
+                        updated = true;
+                    }
+                }
+
+                if updated {
+                    if let Err(err) = answers::save(day.day, &known) {
+                        eprintln!("error: couldn't save {}: {err}", answers::path(day.day).display());
+                        failed = true;
+                    }
+                }
+            }
+
+            println!();
+            println!("{passed} passed, {mismatched} failed, {missing} missing");
+            if failed || mismatched > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+        },
+        Command::Bench { days, samples, warmup, baseline, threshold, save } => {
+            let (loaded, mut failed) = match days.load() {
+                Ok(loaded) => loaded,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE
+                },
+            };
+
+            let baseline = match baseline.map(|path| bench::load_baseline(&path).map_err(|err| (path, err))).transpose() {
+                Ok(baseline) => baseline,
+                Err((path, err)) => {
+                    eprintln!("error: couldn't read baseline {}: {err}", path.display());
+                    return ExitCode::FAILURE
+                },
+            };
+
+            let config = BenchConfig { warmup, samples };
+            let mut benches = Vec::new();
+            for (day, input, params) in loaded.iter() {
+                match day.bench(input, params, config) {
+                    Ok(day_bench) => benches.push(day_bench),
+                    Err(err) => {
+                        eprintln!("error: {err}");
+                        failed = true;
+                    },
+                }
+            }
+            print!("{}", bench::to_table(&benches, baseline.as_deref()));
+
+            if let Some(baseline) = &baseline {
+                let regressions = bench::compare(&benches, baseline, threshold);
+                if !regressions.is_empty() {
+                    println!();
+                    for regression in regressions.iter() {
+                        println!(
+                            "Regression: day {} step {} {:+.1}% ({} -> {})",
+                            regression.day,
+                            regression.step,
+                            regression.change_pct(),
+                            format_ns(regression.baseline_ns),
+                            format_ns(regression.current_ns),
+                        );
+                    }
+                    failed = true;
+                }
+            }
+
+            if let Some(path) = save {
+                if let Err(err) = bench::save_baseline(&path, &benches) {
+                    eprintln!("error: couldn't save baseline {}: {err}", path.display());
+                    failed = true;
+                }
+            }
+
+            if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+        },
+        Command::New { day } => {
+            match scaffold::create(Path::new("."), day) {
+                Ok(written) => {
+                    for path in written {
+                        println!("Wrote {}", path.display());
+                    }
+                    println!("Save your puzzle input to {}", input::default_path(day).display());
+                    ExitCode::SUCCESS
+                },
+                Err(err) => {
+                    eprintln!("error: couldn't create day {day}: {err}");
+                    ExitCode::FAILURE
+                },
+            }
+        },
+        Command::Fetch { days } => {
+            let config = match config::load() {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    return ExitCode::FAILURE
+                },
+            };
+            let client = Client::from_config(&config);
+
+            for day in days {
+                match client::fetch(&client, Path::new(input::INPUT_DIR), day) {
+                    Ok(Fetched::Cached(path)) => println!("Day {day}: already saved at {}", path.display()),
+                    Ok(Fetched::Downloaded(path)) => println!("Day {day}: saved to {}", path.display()),
+                    Err(err) => {
+                        eprintln!("error: day {day}: {err}");
+                        return ExitCode::FAILURE
+                    },
+                }
+            }
+            ExitCode::SUCCESS
+        },
+        Command::Submit { day, part, input, params, answer } => {
+            let config = match config::load() {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    return ExitCode::FAILURE
+                },
+            };
+            let answer = match answer {
+                Some(answer) => answer,
+                None => match solve(day, part, input, &config, &params) {
+                    Ok(answer) => answer,
+                    Err(err) => {
+                        eprintln!("error: {err}");
+                        return ExitCode::FAILURE
+                    },
+                },
+            };
+            let client = Client::from_config(&config);
+
+            println!("Day {day} part {part}: submitting {answer}");
+            match submit::submit(&client, Path::new(submit::ATTEMPT_DIR), day, part, &answer) {
+                Ok(Outcome::Correct) => {
+                    println!("{}", Outcome::Correct);
+                    // Keep the accepted answer for `verify`, unless one is recorded already
+                    if let Ok(mut known) = answers::load(day) {
+                        if known.get(part).is_none() {
+                            known.set(part, answer);
+                            if let Err(err) = answers::save(day, &known) {
+                                eprintln!("error: couldn't save {}: {err}", answers::path(day).display());
+                            }
+                        }
+                    }
+                    ExitCode::SUCCESS
+                },
+                Ok(outcome) => {
+                    println!("{outcome}");
+                    ExitCode::FAILURE
+                },
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    ExitCode::FAILURE
+                },
+            }
+        },
+        Command::Render { day, part, input, params, output, every, scale, delay } => {
+            let config = match config::load() {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    return ExitCode::FAILURE
+                },
+            };
+            let (answer, frames) = render::recording(every, || solve(day, part, input, &config, &params));
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    return ExitCode::FAILURE
+                },
+            };
+            if frames.is_empty() {
+                eprintln!("error: day {day} part {part} doesn't record any frames");
+                return ExitCode::FAILURE
+            }
+
+            match output {
+                Some(path) => match render::save(&frames, &path, scale, delay) {
+                    Ok(written) => println!("Wrote {} frames to {}", frames.len(), written[0].display()),
+                    Err(err) => {
+                        eprintln!("error: couldn't write {}: {err}", path.display());
+                        return ExitCode::FAILURE
+                    },
+                },
+                None => play(&frames, delay),
+            }
+            println!("Day {day} part {part}: {answer}");
+            ExitCode::SUCCESS
+        },
+        Command::Generate { day, seed, scale } => {
+            let Some(solver) = get_day(day) else {
+                eprintln!("error: day {day} is not implemented");
+                return ExitCode::FAILURE
+            };
+            let Some(generated) = solver.generate(seed, scale) else {
+                eprintln!("error: day {day} has no input generator");
+                return ExitCode::FAILURE
+            };
+
+            print!("{}", generated.input);
+            if !generated.params.is_empty() {
+                let params = generated.params.iter()
+                    .map(|(name, value)| format!("-p {name}={value}"))
+                    .collect::<Vec<_>>();
+                eprintln!("note: solve this input with {}", params.join(" "));
+            }
+            ExitCode::SUCCESS
+        },
+        Command::Crosscheck { day, cases, seed, scale, params, timeout } => {
+            let Some(solver) = get_day(day) else {
+                eprintln!("error: day {day} is not implemented");
+                return ExitCode::FAILURE
+            };
+            let config = differential::Config { seed, cases, scale, budget: timeout, overrides: params };
+            match solver.crosscheck(&config) {
+                Ok(agreement) if agreement.parts.is_empty() => {
+                    eprintln!("error: day {day} has no reference solvers");
+                    ExitCode::FAILURE
+                },
+                Ok(agreement) => {
+                    let parts = agreement.parts.iter().map(u8::to_string).collect::<Vec<_>>();
+                    println!("Day {day}: part {} agreed with the reference on {} inputs", parts.join(" and "), agreement.cases);
+                    ExitCode::SUCCESS
+                },
+                Err(err) => {
+                    eprintln!("Day {day}: {err}");
+                    ExitCode::FAILURE
+                },
+            }
+        },
+        Command::Complexity { day, max_scale, limit, timeout, samples, seed, params, max_exponent } => {
+            let Some(solver) = get_day(day) else {
+                eprintln!("error: day {day} is not implemented");
+                return ExitCode::FAILURE
+            };
+            let config = ComplexityConfig { seed, max_scale, samples, limit, budget: timeout, overrides: params };
+            let growths = match solver.profile(&config) {
+                Ok(growths) => growths,
+                Err(err) => {
+                    eprintln!("error: day {day}: {err}");
+                    return ExitCode::FAILURE
+                },
+            };
+            print!("{}", complexity::to_table(&growths));
+
+            let Some(max_exponent) = max_exponent else { return ExitCode::SUCCESS };
+            let too_fast = growths.iter()
+                .filter(|growth| growth.time_exponent().is_some_and(|k| k > max_exponent))
+                .collect::<Vec<_>>();
+            for growth in too_fast.iter() {
+                eprintln!("Step {} grows faster than n^{max_exponent}", growth.step);
+            }
+            if too_fast.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+        },
+        Command::Watch { day, input, params, release, once } => {
+            if once {
+                return watch_once(day, input, &params)
+            }
+
+            let mut stamps = Stamps::default();
+            let mut previous = None;
+            let input_path = input.clone().unwrap_or_else(|| input::default_path(day));
+            println!("Watching day {day}, press Ctrl-C to stop");
+            loop {
+                let current = Stamps::read(&watch::watched_paths(Path::new("."), day, &input_path));
+                let changed = current.changed_since(&stamps);
+                if !changed.is_empty() {
+                    if previous.is_some() {
+                        let changed = changed.iter().map(|path| path.display().to_string()).collect::<Vec<_>>();
+                        println!();
+                        println!("Changed: {}", changed.join(", "));
+                    }
+                    match run_watched(day, input.as_deref(), &params, release) {
+                        Ok(snapshot) => {
+                            print!("{}", snapshot.render(previous.as_ref()));
+                            previous = Some(snapshot);
+                        },
+                        Err(err) => eprintln!("error: {err}, waiting for changes"),
+                    }
+                }
+                stamps = current;
+                std::thread::sleep(Duration::from_millis(watch::POLL_INTERVAL_MS));
+            }
+        },
+    }
+}
+
+
+/// Plays frames in the terminal, redrawing each over the last.
+fn play(frames: &[render::Frame], delay_ms: u32) {
+    for frame in frames {
+        print!("\x1b[H\x1b[2J{}", frame.to_ansi());
+        std::thread::sleep(Duration::from_millis(delay_ms.into()));
+    }
+}
+
+
+/// The child side of `watch`: runs the examples and input with the freshly
+/// built solutions and prints a [`Snapshot`] as JSON.
+fn watch_once(day: u32, input: Option<PathBuf>, overrides: &[(String, i64)]) -> ExitCode {
+    let Some(solver) = get_day(day) else {
+        eprintln!("error: day {day} is not implemented");
+        return ExitCode::FAILURE
+    };
+    let params = config::load()
+        .map_err(|err| err.to_string())
+        .and_then(|config| day_params(solver, &config, &[]))
+        .and_then(|mut params| {
+            for (name, value) in overrides {
+                params.set(name, *value).map_err(|err| format!("day {day}: {err}"))?;
+            }
+            Ok(params)
+        });
+    let params = match params {
+        Ok(params) => params,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE
+        },
+    };
+
+    let input = input::load(day, &InputSource::from_arg(input)).map_err(|err| err.to_string());
+    let snapshot = Snapshot::take(solver, input, &params);
+    println!("{}", serde_json::to_string(&snapshot).unwrap());
+    ExitCode::SUCCESS
+}
+
+
+/// Rebuilds the solutions and runs `watch --once` with them, so edits to the
+/// day's code take effect.
+fn run_watched(day: u32, input: Option<&Path>, overrides: &[(String, i64)], release: bool) -> Result<Snapshot, String> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = std::process::Command::new(cargo);
+    command.args(["run", "--quiet"]);
+    if release {
+        command.arg("--release");
+    }
+    command.args(["--", "watch", &day.to_string(), "--once"]);
+    if let Some(input) = input {
+        command.arg("--input").arg(input);
+    }
+    for (name, value) in overrides {
+        command.arg("--param").arg(format!("{name}={value}"));
+    }
+
+    let output = command.stderr(std::process::Stdio::inherit())
+        .output()
+        .map_err(|err| format!("couldn't run cargo: {err}"))?;
+    if !output.status.success() {
+        return Err("build or run failed".to_string())
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json = stdout.lines().last().unwrap_or_default();
+    serde_json::from_str(json).map_err(|err| format!("couldn't read the answers: {err}"))
+}
+
+
+/// Runs a single day on its input and returns the answer to one part.
+fn solve(
+    day: u32,
+    part: u8,
+    input: Option<PathBuf>,
+    config: &Config,
+    overrides: &[(String, i64)],
+) -> Result<String, String> {
+    let solver = get_day(day)
+        .ok_or_else(|| format!("day {day} is not implemented"))?;
+    let mut params = day_params(solver, config, &[])?;
+    for (name, value) in overrides {
+        params.set(name, *value).map_err(|err| format!("day {day}: {err}"))?;
+    }
+    let input = input::load(day, &InputSource::from_arg(input)).map_err(|err| err.to_string())?;
+    let answer = solver.solve(&input, &params, part).map_err(|err| err.to_string())?;
+    Ok(answer.to_string())
+}
+
+
+/// A day's parameters: its declared defaults, then any set in `aoc.toml`,
+/// then those `overrides` the day declares.
+fn day_params(day: &Day, config: &Config, overrides: &[(String, i64)]) -> Result<Params, String> {
+    let mut params = day.default_params()
+        .with_overrides(config.params(day.day))
+        .map_err(|err| format!("{} [params.day{}]: {err}", config::CONFIG_PATH, day.day))?;
+    for (name, value) in overrides {
+        if params.contains(name) {
+            params.set(name, *value)?;
+        }
+    }
+    Ok(params)
+}
+
+
+/// Parses a positive number of seconds.
+fn parse_timeout(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>().ok()
+        .filter(|secs| secs.is_finite() && *secs > 0.0)
+        .map(Duration::from_secs_f64)
+        .ok_or_else(|| format!("invalid number of seconds `{s}`"))
+}
+
+
+/// Parses `N`, `A..B` or `A..=B` into an inclusive range of days.
+fn parse_days(s: &str) -> Result<RangeInclusive<u32>, String> {
+    let parse = |v: &str| v.trim().parse::<u32>()
+        .map_err(|_| format!("invalid day `{v}`"));
+
+    let range = if let Some((start, end)) = s.split_once("..=") {
+        parse(start)?..=parse(end)?
+    } else if let Some((start, end)) = s.split_once("..") {
+        let end = parse(end)?;
+        if end == 0 {
+            return Err(format!("empty range `{s}`"))
+        }
+        parse(start)?..=(end - 1)
+    } else {
+        let day = parse(s)?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        return Err(format!("empty range `{s}`"))
+    }
+    Ok(range)
+}
@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// Named integer puzzle parameters, such as a grid size or threshold, that
+/// differ between the worked examples and the real puzzle input. Each day
+/// declares its parameters with the defaults for real input in
+/// [`crate::Solution::PARAMS`]; examples, the config file and the command
+/// line can override them by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, i64>,
+    /// The values each parameter accepts, for those limited to some.
+    ranges: BTreeMap<String, RangeInclusive<i64>>,
+}
+
+impl Params {
+    /// The declared parameters at their defaults.
+    pub fn new(declared: &[(&str, i64)]) -> Self {
+        Self {
+            values: declared.iter()
+                .map(|&(name, value)| (name.to_string(), value))
+                .collect(),
+            ranges: BTreeMap::new(),
+        }
+    }
+
+    /// Limits the named parameters to their ranges, so overriding one with a
+    /// value outside it is an error rather than a panic or a hang later on.
+    pub fn with_ranges(mut self, ranges: &[(&str, RangeInclusive<i64>)]) -> Self {
+        self.ranges.extend(ranges.iter().map(|(name, range)| (name.to_string(), range.clone())));
+        self
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// The value of a declared parameter. Panics for undeclared names, which
+    /// are a mistake in the day's code rather than its input.
+    pub fn get(&self, name: &str) -> i64 {
+        match self.values.get(name) {
+            Some(&value) => value,
+            None => panic!("Undeclared parameter `{name}`"),
+        }
+    }
+
+    /// Overrides a declared parameter, rejecting names the day doesn't use
+    /// and values outside the parameter's range.
+    pub fn set(&mut self, name: &str, value: i64) -> Result<(), String> {
+        if let Some(slot) = self.values.get_mut(name) {
+            if let Some(range) = self.ranges.get(name).filter(|range| !range.contains(&value)) {
+                let expected = match (*range.start(), *range.end()) {
+                    (start, i64::MAX) => format!("at least {start}"),
+                    (start, end) => format!("between {start} and {end}"),
+                };
+                return Err(format!("parameter `{name}` must be {expected}, found {value}"))
+            }
+            *slot = value;
+            return Ok(())
+        }
+        if self.values.is_empty() {
+            return Err(format!("unknown parameter `{name}`, this day takes none"))
+        }
+        Err(format!(
+            "unknown parameter `{name}`, expected one of {}",
+            self.names().map(|name| format!("`{name}`")).collect::<Vec<_>>().join(", ")
+        ))
+    }
+
+    pub fn with_overrides<'a>(mut self, overrides: impl IntoIterator<Item = (&'a str, i64)>) -> Result<Self, String> {
+        for (name, value) in overrides {
+            self.set(name, value)?;
+        }
+        Ok(self)
+    }
+}
+
+
+/// Parses a `NAME=VALUE` override from the command line.
+pub fn parse_assignment(s: &str) -> Result<(String, i64), String> {
+    let (name, value) = s.split_once('=')
+        .ok_or_else(|| format!("expected `NAME=VALUE`, found `{s}`"))?;
+    let value = value.trim().parse()
+        .map_err(|_| format!("invalid value `{}` for `{}`", value.trim(), name.trim()))?;
+    Ok((name.trim().to_string(), value))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_overrides() {
+        let params = Params::new(&[("width", 101), ("height", 103)])
+            .with_overrides([("width", 11)])
+            .unwrap();
+        assert_eq!((params.get("width"), params.get("height")), (11, 103));
+
+        let err = Params::new(&[("width", 101)]).with_overrides([("size", 7)]).unwrap_err();
+        assert_eq!(err, "unknown parameter `size`, expected one of `width`");
+    }
+
+    #[test]
+    fn test_ranges() {
+        let params = Params::new(&[("width", 101), ("blinks", 25)])
+            .with_ranges(&[("width", 1..=i64::MAX), ("blinks", 0..=100)]);
+        assert_eq!(params.clone().with_overrides([("width", 1), ("blinks", 100)]).unwrap().get("width"), 1);
+
+        let err = params.clone().with_overrides([("width", 0)]).unwrap_err();
+        assert_eq!(err, "parameter `width` must be at least 1, found 0");
+        let err = params.with_overrides([("blinks", -1)]).unwrap_err();
+        assert_eq!(err, "parameter `blinks` must be between 0 and 100, found -1");
+    }
+
+    #[test]
+    fn test_parse_assignment() {
+        assert_eq!(parse_assignment("threshold = 50"), Ok(("threshold".to_string(), 50)));
+        assert!(parse_assignment("threshold").is_err());
+        assert!(parse_assignment("threshold=lots").is_err());
+    }
+}
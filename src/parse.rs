@@ -0,0 +1,155 @@
+use std::fmt;
+use std::str::FromStr;
+
+use nom::IResult;
+
+/// How many characters either side of the error position the snippet keeps.
+const SNIPPET_RADIUS: usize = 30;
+
+/// A problem found while parsing a puzzle input, pointing at the offending
+/// text. `line` and `col` are 1-based, with `col` counted in chars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u32>,
+    pub line: usize,
+    pub col: usize,
+    /// The text around the error, from the offending line only.
+    pub snippet: String,
+    /// Index of the error position within `snippet`, in chars.
+    pub snippet_col: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error at byte `offset` into `input`.
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |idx| offset + idx);
+        let line_text = input[line_start..line_end].trim_end_matches('\r');
+
+        let line = input[..line_start].matches('\n').count() + 1;
+        let col_idx = input[line_start..offset].chars().count();
+        let snippet_start = col_idx.saturating_sub(SNIPPET_RADIUS);
+        let snippet = line_text.chars()
+            .skip(snippet_start)
+            .take(SNIPPET_RADIUS * 2)
+            .collect();
+
+        Self {
+            day: None,
+            line,
+            col: col_idx + 1,
+            snippet,
+            snippet_col: col_idx - snippet_start,
+            message: message.into(),
+        }
+    }
+
+    /// An error at the start of `rest`, which must be a slice of `input`,
+    /// such as one of its lines or a parser's remaining input. Falls back to
+    /// the start of `input` for unrelated slices.
+    pub fn at(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let offset = (rest.as_ptr() as usize).checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(0);
+        Self::at_offset(input, offset, message)
+    }
+
+    /// An error about something missing from the input as a whole, reported
+    /// at its end.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        Self::at_offset(input, input.trim_end().len(), message)
+    }
+
+    pub fn with_day(self, day: u32) -> Self {
+        Self { day: Some(day), ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}: ")?;
+        }
+        writeln!(f, "parse error at line {}, column {}: {}", self.line, self.col, self.message)?;
+        writeln!(f, "    {}", self.snippet)?;
+        write!(f, "    {}^", " ".repeat(self.snippet_col))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+
+/// Parses `s`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, s: &str) -> Result<T, ParseError> {
+    s.trim().parse()
+        .map_err(|_| ParseError::at(input, s, format!("expected a number, found {:?}", s.trim())))
+}
+
+
+/// Converts a nom failure on a slice of `input` into a [`ParseError`].
+pub fn nom_error(input: &str, err: nom::Err<nom::error::Error<&str>>) -> ParseError {
+    match err {
+        nom::Err::Error(err) | nom::Err::Failure(err) => {
+            ParseError::at(input, err.input, format!("unexpected input (expected {})", err.code.description()))
+        },
+        nom::Err::Incomplete(_) => ParseError::at_end(input, "unexpected end of input"),
+    }
+}
+
+
+/// Unwraps the result of running a nom parser over a slice of `input`,
+/// rejecting anything but whitespace left over afterwards.
+pub fn finish<'a, T>(input: &'a str, result: IResult<&'a str, T>) -> Result<T, ParseError> {
+    let (rest, value) = result.map_err(|err| nom_error(input, err))?;
+
+    let rest = rest.trim_start();
+    if !rest.is_empty() {
+        return Err(ParseError::at(input, rest, "unexpected trailing input"))
+    }
+    Ok(value)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use nom::character::complete::digit1;
+
+    const INPUT: &str = "12 34\n56 7x\n";
+
+    #[test]
+    fn test_position() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let err = ParseError::at(INPUT, &line[4..], "unexpected character 'x'");
+        assert_eq!((err.line, err.col), (2, 5));
+        assert_eq!(err.snippet, "56 7x");
+        assert_eq!(
+            err.with_day(3).to_string(),
+            "day 3: parse error at line 2, column 5: unexpected character 'x'\n    56 7x\n        ^"
+        );
+    }
+
+    #[test]
+    fn test_number() {
+        let line = INPUT.lines().nth(1).unwrap();
+        assert_eq!(number::<u32>(INPUT, &line[..2]), Ok(56));
+
+        let err = number::<u32>(INPUT, &line[3..]).unwrap_err();
+        assert_eq!((err.line, err.col), (2, 4));
+    }
+
+    #[test]
+    fn test_finish() {
+        assert_eq!(finish(INPUT, digit1::<_, nom::error::Error<_>>(&INPUT[..2])), Ok("12"));
+
+        let err = finish(INPUT, digit1::<_, nom::error::Error<_>>(INPUT)).unwrap_err();
+        assert_eq!((err.line, err.col), (1, 4));
+        assert_eq!(err.message, "unexpected trailing input");
+
+        let err = finish(INPUT, digit1::<_, nom::error::Error<_>>(&INPUT[2..])).unwrap_err();
+        assert_eq!((err.line, err.col), (1, 3));
+    }
+}
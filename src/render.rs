@@ -0,0 +1,356 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::geom::Point;
+use crate::grid::{Grid, Idx};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(110, 110, 110);
+    pub const RED: Rgb = Rgb(230, 60, 50);
+    pub const GREEN: Rgb = Rgb(70, 200, 80);
+    pub const BLUE: Rgb = Rgb(70, 120, 230);
+    pub const YELLOW: Rgb = Rgb(240, 200, 50);
+}
+
+/// How one cell is drawn: its glyph in the terminal, a solid square of its
+/// color in images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tile {
+    pub glyph: char,
+    pub color: Rgb,
+}
+
+impl Tile {
+    pub const EMPTY: Tile = Tile::new('.', Rgb::BLACK);
+    pub const WALL: Tile = Tile::new('#', Rgb::GREY);
+
+    pub const fn new(glyph: char, color: Rgb) -> Self {
+        Self { glyph, color }
+    }
+}
+
+/// One picture of a grid, with `x` across and `y` down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    tiles: Vec<Tile>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, fill: Tile) -> Self {
+        Self { width, height, tiles: vec![fill; width * height] }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, tile: impl Fn(Idx, &T) -> Tile) -> Self {
+        let (height, width) = grid.dim();
+        let tiles = grid.indexed_iter().map(|(idx, val)| tile(idx, val)).collect();
+        Self { width, height, tiles }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        let (x, y) = (usize::try_from(point.x).ok()?, usize::try_from(point.y).ok()?);
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get(&self, point: Point) -> Option<Tile> {
+        self.index(point).map(|idx| self.tiles[idx])
+    }
+
+    /// Draws `tile` at `point`, ignoring points outside the frame.
+    pub fn set(&mut self, point: Point, tile: Tile) {
+        if let Some(idx) = self.index(point) {
+            self.tiles[idx] = tile;
+        }
+    }
+
+    /// Draws `tile` over every point, such as a path or a set of robots.
+    pub fn overlay(mut self, points: impl IntoIterator<Item = Point>, tile: Tile) -> Self {
+        for point in points {
+            self.set(point, tile);
+        }
+        self
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Tile]> {
+        self.tiles.chunks(self.width.max(1))
+    }
+
+    /// The glyphs alone, one line per row.
+    pub fn to_text(&self) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            out.extend(row.iter().map(|tile| tile.glyph));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// The glyphs in their colors, using 24-bit terminal escape codes.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.rows() {
+            let mut current = None;
+            for tile in row {
+                if current != Some(tile.color) {
+                    let Rgb(r, g, b) = tile.color;
+                    // Black would vanish on most terminals, so draw it dim instead
+                    if tile.color == Rgb::BLACK {
+                        write!(out, "\x1b[0;2m").unwrap();
+                    } else {
+                        write!(out, "\x1b[0;38;2;{r};{g};{b}m").unwrap();
+                    }
+                    current = Some(tile.color);
+                }
+                out.push(tile.glyph);
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        out
+    }
+
+    /// Packed RGB pixels with each cell drawn as a `scale` pixel square.
+    pub fn to_rgb(&self, scale: usize) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.width * self.height * scale * scale * 3);
+        for row in self.rows() {
+            for _ in 0..scale {
+                for tile in row {
+                    let Rgb(r, g, b) = tile.color;
+                    for _ in 0..scale {
+                        pixels.extend([r, g, b]);
+                    }
+                }
+            }
+        }
+        pixels
+    }
+
+    /// Pixel dimensions at `scale`.
+    pub fn image_size(&self, scale: usize) -> (usize, usize) {
+        (self.width * scale, self.height * scale)
+    }
+
+    pub fn write_ppm(&self, mut out: impl Write, scale: usize) -> io::Result<()> {
+        let (width, height) = self.image_size(scale);
+        write!(out, "P6\n{width} {height}\n255\n")?;
+        out.write_all(&self.to_rgb(scale))
+    }
+
+    pub fn write_png(&self, out: impl Write, scale: usize) -> io::Result<()> {
+        let (width, height) = self.image_size(scale);
+        let mut encoder = png::Encoder::new(out, image_dim(width)?, image_dim(height)?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&self.to_rgb(scale)).map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+}
+
+
+fn image_dim(pixels: usize) -> io::Result<u32> {
+    pixels.try_into().map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "image too large"))
+}
+
+
+/// Writes the frames as a looping animated GIF, each shown for `delay_ms`.
+/// All frames must be the same size.
+pub fn write_gif(frames: &[Frame], out: impl Write, scale: usize, delay_ms: u32) -> io::Result<()> {
+    let Some(first) = frames.first() else {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "no frames to write"))
+    };
+    let (width, height) = first.image_size(scale);
+    let (width, height) = (gif_dim(width)?, gif_dim(height)?);
+    if frames.iter().any(|frame| frame.image_size(scale) != first.image_size(scale)) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "frames differ in size"))
+    }
+
+    // Grid pictures rarely use many colors, so share one exact palette when
+    // they fit in a GIF's 256 and let the encoder quantize otherwise
+    let mut palette = HashMap::new();
+    for tile in frames.iter().flat_map(|frame| frame.tiles.iter()) {
+        let next = palette.len();
+        palette.entry(tile.color).or_insert(next);
+    }
+    let shared = palette.len() <= 256;
+    let mut global_palette = vec![0; palette.len() * 3];
+    for (&Rgb(r, g, b), &idx) in palette.iter() {
+        global_palette[idx * 3..idx * 3 + 3].copy_from_slice(&[r, g, b]);
+    }
+
+    let mut encoder = gif::Encoder::new(out, width, height, if shared { &global_palette } else { &[] })
+        .map_err(io::Error::other)?;
+    encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+
+    let delay = (delay_ms / 10).try_into().unwrap_or(u16::MAX);
+    for frame in frames {
+        let mut gif_frame = if shared {
+            let scaled = frame.to_rgb(scale);
+            let indices = scaled.chunks(3)
+                .map(|px| palette[&Rgb(px[0], px[1], px[2])] as u8)
+                .collect::<Vec<_>>();
+            gif::Frame::from_indexed_pixels(width, height, indices, None)
+        } else {
+            gif::Frame::from_rgb_speed(width, height, &frame.to_rgb(scale), 10)
+        };
+        gif_frame.delay = delay;
+        encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+
+fn gif_dim(pixels: usize) -> io::Result<u16> {
+    pixels.try_into().map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "image too large for a GIF"))
+}
+
+
+/// Saves frames in the format the extension of `path` names: an animated
+/// `.gif`, plain `.txt`, or one `.png` or `.ppm` per frame, numbered when
+/// there are several. Returns the files written.
+pub fn save(frames: &[Frame], path: &Path, scale: usize, delay_ms: u32) -> io::Result<Vec<PathBuf>> {
+    let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or_default().to_ascii_lowercase();
+    match extension.as_str() {
+        "gif" => {
+            write_gif(frames, io::BufWriter::new(std::fs::File::create(path)?), scale, delay_ms)?;
+            Ok(vec![path.to_path_buf()])
+        },
+        "txt" => {
+            let text = frames.iter().map(Frame::to_text).collect::<Vec<_>>().join("\n");
+            std::fs::write(path, text)?;
+            Ok(vec![path.to_path_buf()])
+        },
+        "png" | "ppm" => {
+            let mut written = Vec::new();
+            for (idx, frame) in frames.iter().enumerate() {
+                let frame_path = if frames.len() == 1 {
+                    path.to_path_buf()
+                } else {
+                    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+                    path.with_file_name(format!("{stem}_{idx:04}.{extension}"))
+                };
+                let out = io::BufWriter::new(std::fs::File::create(&frame_path)?);
+                match extension.as_str() {
+                    "png" => frame.write_png(out, scale)?,
+                    _ => frame.write_ppm(out, scale)?,
+                }
+                written.push(frame_path);
+            }
+            Ok(written)
+        },
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unsupported image format `{}`, expected .gif, .png, .ppm or .txt", path.display()),
+        )),
+    }
+}
+
+
+/// Collects the frames solvers push with [`record`], keeping one in every
+/// `every`.
+struct Recorder {
+    every: usize,
+    seen: usize,
+    frames: Vec<Frame>,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+
+/// Whether frames pushed on this thread are being kept.
+pub fn is_recording() -> bool {
+    RECORDER.with_borrow(Option::is_some)
+}
+
+
+/// Pushes a frame to the recording in progress on this thread, if any.
+/// `frame` only runs for frames that are kept, so solvers can call this
+/// every step at no cost when nothing is recording.
+pub fn record(frame: impl FnOnce() -> Frame) {
+    RECORDER.with_borrow_mut(|recorder| {
+        if let Some(recorder) = recorder {
+            if recorder.seen % recorder.every == 0 {
+                recorder.frames.push(frame());
+            }
+            recorder.seen += 1;
+        }
+    });
+}
+
+
+/// Runs `f`, returning its result and every `every`th frame it recorded.
+pub fn recording<T>(every: usize, f: impl FnOnce() -> T) -> (T, Vec<Frame>) {
+    let outer = RECORDER.replace(Some(Recorder { every: every.max(1), seen: 0, frames: Vec::new() }));
+    let result = f();
+    let recorder = RECORDER.replace(outer).unwrap();
+    (result, recorder.frames)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn frame() -> Frame {
+        let grid = Grid::parse("#.\n.#\n", |c| Some(c == '#')).unwrap();
+        Frame::from_grid(&grid, |_, &wall| if wall { Tile::WALL } else { Tile::EMPTY })
+            .overlay([Point::new(1, 0), Point::new(5, 5)], Tile::new('@', Rgb::RED))
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(frame().to_text(), "#@\n.#\n");
+        assert_eq!(frame().get(Point::new(1, 0)), Some(Tile::new('@', Rgb::RED)));
+        assert!(frame().to_ansi().starts_with("\x1b[0;38;2;110;110;110m#\x1b[0;38;2;230;60;50m@\x1b[0m\n"));
+    }
+
+    #[test]
+    fn test_images() {
+        let mut ppm = Vec::new();
+        frame().write_ppm(&mut ppm, 2).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+        assert_eq!(&ppm[11..17], &[110, 110, 110, 110, 110, 110]);
+
+        let mut png = Vec::new();
+        frame().write_png(&mut png, 2).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        let mut gif = Vec::new();
+        write_gif(&[frame(), Frame::new(2, 2, Tile::EMPTY)], &mut gif, 2, 100).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        assert!(write_gif(&[frame(), Frame::new(3, 2, Tile::EMPTY)], &mut Vec::new(), 2, 100).is_err());
+    }
+
+    #[test]
+    fn test_recording() {
+        record(|| unreachable!());
+        let (answer, frames) = recording(2, || {
+            for width in 1..=5 {
+                record(|| Frame::new(width, 1, Tile::EMPTY));
+            }
+            42
+        });
+        assert_eq!(answer, 42);
+        assert_eq!(frames.iter().map(Frame::width).collect::<Vec<_>>(), [1, 3, 5]);
+        assert!(!is_recording());
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt::Write;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::heap::HeapStats;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable, aligned table
+    Table,
+    Json,
+    Csv,
+}
+
+/// Timings and answers for every day in a single run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Report {
+    pub days: Vec<DayReport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayReport {
+    pub day: u32,
+    pub parse_ns: u64,
+    /// Heap use while parsing, if built with the `count-allocs` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse_heap: Option<HeapStats>,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartReport {
+    pub part: u8,
+    /// Empty if the part timed out.
+    pub answer: String,
+    pub solve_ns: u64,
+    /// Whether the part was stopped for running past its time budget.
+    #[serde(default)]
+    pub timed_out: bool,
+    /// Heap use while solving, if built with the `count-allocs` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heap: Option<HeapStats>,
+}
+
+impl PartReport {
+    /// The answer, or a note that there is none.
+    pub fn display_answer(&self) -> &str {
+        if self.timed_out { "timed out" } else { &self.answer }
+    }
+}
+
+impl Report {
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Table => self.to_table(),
+            Format::Json => self.to_json(),
+            Format::Csv => self.to_csv(),
+        }
+    }
+
+    /// Whether any step has heap counts to show.
+    fn has_heap(&self) -> bool {
+        self.days.iter().any(|d| d.parse_heap.is_some() || d.parts.iter().any(|p| p.heap.is_some()))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// One row per part, with the day's parse time repeated on each row.
+    /// Heap counts, when there are any, follow in the same way.
+    pub fn to_csv(&self) -> String {
+        let has_heap = self.has_heap();
+        let mut out = String::from("day,part,answer,parse_ns,solve_ns");
+        if has_heap {
+            out.push_str(",parse_allocations,parse_allocated_bytes,parse_peak_bytes,allocations,allocated_bytes,peak_bytes");
+        }
+        out.push('\n');
+
+        for day in self.days.iter() {
+            for part in day.parts.iter() {
+                write!(
+                    out,
+                    "{},{},{},{},{}",
+                    day.day, part.part, csv_field(part.display_answer()), day.parse_ns, part.solve_ns
+                ).unwrap();
+                if has_heap {
+                    for heap in [day.parse_heap, part.heap] {
+                        match heap {
+                            Some(heap) => write!(out, ",{},{},{}", heap.allocations, heap.allocated_bytes, heap.peak_bytes),
+                            None => write!(out, ",,,"),
+                        }.unwrap();
+                    }
+                }
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    pub fn to_table(&self) -> String {
+        let answer_width = self.days.iter()
+            .flat_map(|d| d.parts.iter())
+            .map(|p| p.display_answer().chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("Answer".len());
+
+        let has_heap = self.has_heap();
+        let part_width = if has_heap { "parse".len() } else { "Part".len() };
+
+        let mut out = String::new();
+        write!(out, "{:>3}  {:>part_width$}  {:<answer_width$}  {:>10}  {:>10}", "Day", "Part", "Answer", "Parse", "Solve").unwrap();
+        if has_heap {
+            write!(out, "  {:>10}  {:>10}  {:>10}", "Allocs", "Allocated", "Peak heap").unwrap();
+        }
+        out.push('\n');
+
+        for day in self.days.iter() {
+            if has_heap {
+                // The parse's heap counts get a row of their own
+                writeln!(
+                    out,
+                    "{:>3}  {:>part_width$}  {:<answer_width$}  {:>10}  {:>10}{}",
+                    day.day, "parse", "", format_ns(day.parse_ns), "", heap_columns(day.parse_heap)
+                ).unwrap();
+            }
+            for part in day.parts.iter() {
+                write!(
+                    out,
+                    "{:>3}  {:>part_width$}  {:<answer_width$}  {:>10}  {:>10}",
+                    day.day, part.part, part.display_answer(), format_ns(day.parse_ns), format_ns(part.solve_ns)
+                ).unwrap();
+                if has_heap {
+                    out.push_str(&heap_columns(part.heap));
+                }
+                out.push('\n');
+            }
+        }
+
+        let total_ns = self.days.iter()
+            .map(|d| d.parse_ns + d.parts.iter().map(|p| p.solve_ns).sum::<u64>())
+            .sum::<u64>();
+        writeln!(out, "Total: {}", format_ns(total_ns)).unwrap();
+        out
+    }
+}
+
+
+/// Formats a nanosecond duration with a unit suited to its magnitude.
+pub fn format_ns(ns: u64) -> String {
+    match ns {
+        0..1_000 => format!("{ns} ns"),
+        1_000..1_000_000 => format!("{:.2} µs", ns as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.2} ms", ns as f64 / 1e6),
+        _ => format!("{:.3} s", ns as f64 / 1e9),
+    }
+}
+
+
+/// Formats a byte count with a binary unit suited to its magnitude.
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{bytes} B"),
+        1024..1_048_576 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        1_048_576..1_073_741_824 => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+        _ => format!("{:.2} GiB", bytes as f64 / 1_073_741_824.0),
+    }
+}
+
+
+fn heap_columns(heap: Option<HeapStats>) -> String {
+    match heap {
+        Some(heap) => format!(
+            "  {:>10}  {:>10}  {:>10}",
+            heap.allocations, format_bytes(heap.allocated_bytes), format_bytes(heap.peak_bytes)
+        ),
+        None => format!("  {:>10}  {:>10}  {:>10}", "-", "-", "-"),
+    }
+}
+
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Report {
+        Report {
+            days: vec![DayReport {
+                day: 17,
+                parse_ns: 1_500,
+                parse_heap: None,
+                parts: vec![
+                    PartReport { part: 1, answer: "4,6,3".to_string(), solve_ns: 42, timed_out: false, heap: None },
+                    PartReport { part: 2, answer: "117440".to_string(), solve_ns: 2_000_000, timed_out: false, heap: None },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            example().to_csv(),
+            "day,part,answer,parse_ns,solve_ns\n17,1,\"4,6,3\",1500,42\n17,2,117440,1500,2000000\n"
+        );
+    }
+
+    #[test]
+    fn test_heap_columns() {
+        let mut report = example();
+        report.days[0].parse_heap = Some(HeapStats { allocations: 3, allocated_bytes: 2048, peak_bytes: 1024 });
+        report.days[0].parts[0].heap = Some(HeapStats { allocations: 1, allocated_bytes: 10, peak_bytes: 0 });
+
+        let csv = report.to_csv();
+        assert!(csv.starts_with("day,part,answer,parse_ns,solve_ns,parse_allocations,"));
+        assert!(csv.contains("\n17,1,\"4,6,3\",1500,42,3,2048,1024,1,10,0\n"));
+        assert!(csv.ends_with(",3,2048,1024,,,\n"));
+
+        let table = report.to_table();
+        assert!(table.lines().next().unwrap().ends_with("Peak heap"));
+        assert!(table.contains("parse"));
+        assert!(table.contains("2.0 KiB"));
+        assert!(!example().to_table().contains("Allocs"));
+
+        let json = report.to_json();
+        assert!(json.contains("\"allocated_bytes\": 2048"));
+        assert!(!example().to_json().contains("heap"));
+        let round_trip: Report = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_trip.days[0].parse_heap, report.days[0].parse_heap);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let report: Report = serde_json::from_str(&example().to_json()).unwrap();
+        assert_eq!(report.days[0].parts[1].answer, "117440");
+        assert_eq!(report.days[0].parse_ns, 1_500);
+    }
+
+    #[test]
+    fn test_format_ns() {
+        assert_eq!(format_ns(999), "999 ns");
+        assert_eq!(format_ns(1_500), "1.50 µs");
+        assert_eq!(format_ns(2_000_000), "2.00 ms");
+        assert_eq!(format_ns(3_000_000_000), "3.000 s");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+        assert_eq!(format_bytes(5 << 30), "5.00 GiB");
+    }
+}
@@ -0,0 +1,168 @@
+use std::time::{Duration, Instant};
+
+use crate::Solution;
+use crate::answer::Answer;
+use crate::bench::{self, BenchConfig, DayBench, PartBench};
+use crate::cancel;
+use crate::complexity::{self, ComplexityConfig, Growth};
+use crate::differential::{self, Agreement, CheckError};
+use crate::examples;
+use crate::generate::{Generated, Rng};
+use crate::heap;
+use crate::log;
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::report::{DayReport, PartReport};
+
+/// A registered day, with its solver erased behind a plain function pointer.
+pub struct Day {
+    pub day: u32,
+    /// The day's declared parameters and their defaults.
+    pub params: &'static [(&'static str, i64)],
+    default_params: fn() -> Params,
+    run: fn(u32, &str, &Params, Option<Duration>) -> Result<DayReport, ParseError>,
+    bench: fn(u32, &str, &Params, BenchConfig) -> Result<DayBench, ParseError>,
+    solve: fn(u32, &str, &Params, u8) -> Result<Answer, ParseError>,
+    examples: fn() -> Vec<examples::Outcome>,
+    generate: fn(&mut Rng, u32) -> Option<Generated>,
+    crosscheck: fn(&differential::Config) -> Result<Agreement, CheckError>,
+    profile: fn(&ComplexityConfig) -> Result<Vec<Growth>, String>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(day: u32) -> Self {
+        Self {
+            day,
+            params: S::PARAMS,
+            default_params: S::default_params,
+            run: run::<S>,
+            bench: bench::<S>,
+            solve: solve::<S>,
+            examples: examples::run::<S>,
+            generate: S::generate,
+            crosscheck: differential::check::<S>,
+            profile: complexity::profile::<S>,
+        }
+    }
+
+    /// The declared parameters at their defaults, see
+    /// [`Solution::default_params`].
+    pub fn default_params(&self) -> Params {
+        (self.default_params)()
+    }
+
+    pub fn run(&self, input: &str, params: &Params) -> Result<DayReport, ParseError> {
+        self.run_with_budget(input, params, None)
+    }
+
+    /// Runs the day, stopping any part still going after `budget` and
+    /// reporting it as timed out. Parts only stop where they call
+    /// [`cancel::check`].
+    pub fn run_with_budget(&self, input: &str, params: &Params, budget: Option<Duration>) -> Result<DayReport, ParseError> {
+        log::with_day(self.day, || (self.run)(self.day, input, params, budget))
+    }
+
+    pub fn bench(&self, input: &str, params: &Params, config: BenchConfig) -> Result<DayBench, ParseError> {
+        log::with_day(self.day, || (self.bench)(self.day, input, params, config))
+    }
+
+    /// Parses the input and solves just one part, untimed.
+    pub fn solve(&self, input: &str, params: &Params, part: u8) -> Result<Answer, ParseError> {
+        log::with_day(self.day, || (self.solve)(self.day, input, params, part))
+    }
+
+    /// Runs the day's worked examples, see [`examples::run`].
+    pub fn examples(&self) -> Vec<examples::Outcome> {
+        log::with_day(self.day, self.examples)
+    }
+
+    /// A random input for this day, the same every time for a given `seed`
+    /// and `scale`. `None` if the day has no generator.
+    pub fn generate(&self, seed: u64, scale: u32) -> Option<Generated> {
+        (self.generate)(&mut Rng::new(seed), scale.max(1))
+    }
+
+    /// Checks the parts against the day's reference solvers on generated
+    /// inputs, see [`differential::check`].
+    pub fn crosscheck(&self, config: &differential::Config) -> Result<Agreement, CheckError> {
+        log::with_day(self.day, || (self.crosscheck)(config))
+    }
+
+    /// Measures how the parse and parts scale with generated input size,
+    /// see [`complexity::profile`].
+    pub fn profile(&self, config: &ComplexityConfig) -> Result<Vec<Growth>, String> {
+        log::with_day(self.day, || (self.profile)(config))
+    }
+}
+
+
+fn run<S: Solution>(day: u32, input: &str, params: &Params, budget: Option<Duration>) -> Result<DayReport, ParseError> {
+    let ((parsed, parse_duration), parse_heap) = heap::measure(|| timed(|| S::parse(input)));
+    let parsed = parsed.map_err(|err| err.with_day(day))?;
+    let p1 = run_part(day, 1, budget, || S::part1(&parsed, params));
+    let p2 = run_part(day, 2, budget, || S::part2(&parsed, params));
+
+    Ok(DayReport {
+        day,
+        parse_ns: as_ns(parse_duration),
+        parse_heap,
+        parts: vec![p1, p2],
+    })
+}
+
+
+fn solve<S: Solution>(day: u32, input: &str, params: &Params, part: u8) -> Result<Answer, ParseError> {
+    let parsed = S::parse(input).map_err(|err| err.with_day(day))?;
+    match part {
+        1 => Ok(S::part1(&parsed, params)),
+        2 => Ok(S::part2(&parsed, params)),
+        _ => panic!("Invalid part {part}"),
+    }
+}
+
+
+fn run_part(day: u32, part: u8, budget: Option<Duration>, solve: impl FnOnce() -> Answer + Send) -> PartReport {
+    let solve = || heap::measure(solve);
+    let (answer, duration) = match budget {
+        Some(budget) => timed(|| cancel::with_budget(budget, || log::with_day(day, solve))),
+        None => timed(|| Some(solve())),
+    };
+    let (answer, heap) = answer.unzip();
+
+    PartReport {
+        part,
+        timed_out: answer.is_none(),
+        answer: answer.map_or_else(String::new, |answer| answer.to_string()),
+        solve_ns: as_ns(duration),
+        heap: heap.flatten(),
+    }
+}
+
+
+fn bench<S: Solution>(day: u32, input: &str, params: &Params, config: BenchConfig) -> Result<DayBench, ParseError> {
+    let parsed = S::parse(input).map_err(|err| err.with_day(day))?;
+    let parse = bench::sample(config, || S::parse(input));
+    let p1 = bench::sample(config, || S::part1(&parsed, params));
+    let p2 = bench::sample(config, || S::part2(&parsed, params));
+
+    Ok(DayBench {
+        day,
+        parse,
+        parts: vec![
+            PartBench { part: 1, stats: p1 },
+            PartBench { part: 2, stats: p2 },
+        ],
+    })
+}
+
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+
+fn as_ns(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
@@ -0,0 +1,143 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answers;
+
+/// The skeleton every new day starts from, with `DayX` as the type name.
+pub const TEMPLATE: &str = include_str!("day_x/mod.rs");
+
+const LIB_PATH: &str = "src/lib.rs";
+
+
+/// The template with its placeholder renamed for `day`.
+pub fn render(day: u32) -> String {
+    TEMPLATE.replace("DayX", &format!("Day{day}"))
+}
+
+
+/// Creates `src/day_N/` with the template module and an empty example,
+/// an empty answers file, and registers the day in `src/lib.rs`. Paths are
+/// relative to `root`. Returns the files written.
+pub fn create(root: &Path, day: u32) -> io::Result<Vec<PathBuf>> {
+    let module_dir = root.join("src").join(format!("day_{day}"));
+    if module_dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module_dir.display()),
+        ))
+    }
+
+    let lib_path = root.join(LIB_PATH);
+    let lib = std::fs::read_to_string(&lib_path)?;
+    let lib = register(&lib, day)
+        .map_err(|msg| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {msg}", lib_path.display())))?;
+
+    std::fs::create_dir_all(&module_dir)?;
+    let module_path = module_dir.join("mod.rs");
+    std::fs::write(&module_path, render(day))?;
+    let example_path = module_dir.join("example");
+    std::fs::write(&example_path, "")?;
+    let mut written = vec![module_path, example_path];
+
+    let answers_path = root.join(answers::path(day));
+    if !answers_path.exists() {
+        std::fs::create_dir_all(root.join(answers::ANSWER_DIR))?;
+        std::fs::write(&answers_path, "")?;
+        written.push(answers_path);
+    }
+
+    std::fs::write(&lib_path, lib)?;
+    written.push(lib_path);
+
+    Ok(written)
+}
+
+
+/// Adds `pub mod day_N;` and the matching `DAYS` entry to the source of
+/// `lib.rs`, each placed in day order.
+pub fn register(lib: &str, day: u32) -> Result<String, String> {
+    let mut lines = lib.lines().map(str::to_string).collect::<Vec<_>>();
+
+    let mod_line = format!("pub mod day_{day};");
+    let entry_line = format!("    Day::new::<day_{day}::Day{day}>({day}),");
+    if lines.contains(&mod_line) {
+        return Err(format!("day {day} is already registered"))
+    }
+
+    let mod_idx = insert_point(&lines, day, |line| {
+        line.strip_prefix("pub mod day_")?.strip_suffix(';')?.parse().ok()
+    }).ok_or("no `pub mod day_N;` lines found")?;
+    lines.insert(mod_idx, mod_line);
+
+    let entry_idx = insert_point(&lines, day, |line| {
+        let (_, rest) = line.trim().strip_prefix("Day::new::<")?.split_once(">(")?;
+        rest.strip_suffix("),")?.parse().ok()
+    }).ok_or("no `Day::new` entries found in DAYS")?;
+    lines.insert(entry_idx, entry_line);
+
+    let mut lib = lines.join("\n");
+    lib.push('\n');
+    Ok(lib)
+}
+
+
+/// The line index to insert `day` at among the lines `day_of` recognizes,
+/// keeping them sorted.
+fn insert_point(lines: &[String], day: u32, day_of: impl Fn(&str) -> Option<u32>) -> Option<usize> {
+    let days = lines.iter()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx, day_of(line)?)))
+        .collect::<Vec<_>>();
+
+    let (last_idx, _) = *days.last()?;
+    let idx = days.iter()
+        .find(|(_, d)| *d > day)
+        .map_or(last_idx + 1, |(idx, _)| *idx);
+    Some(idx)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const LIB: &str = "\
+pub mod day_1;
+pub mod day_3;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day_1::Day1>(1),
+    Day::new::<day_3::Day3>(3),
+];
+";
+
+    #[test]
+    fn test_register() {
+        let lib = register(LIB, 2).unwrap();
+        assert_eq!(lib, "\
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day_1::Day1>(1),
+    Day::new::<day_2::Day2>(2),
+    Day::new::<day_3::Day3>(3),
+];
+");
+
+        let lib = register(LIB, 21).unwrap();
+        assert!(lib.contains("pub mod day_3;\npub mod day_21;\n"));
+        assert!(lib.contains("(3),\n    Day::new::<day_21::Day21>(21),\n];"));
+
+        assert!(register(LIB, 3).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let module = render(21);
+        assert!(module.contains("pub struct Day21;"));
+        assert!(module.contains("impl Solution for Day21 {"));
+        assert!(!module.contains("DayX"));
+    }
+}
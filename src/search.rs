@@ -0,0 +1,281 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The outcome of an exhaustive search from a single start node: the best
+/// cost to every reached node, plus every predecessor that achieves it.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    start: N,
+    dist: FxHashMap<N, C>,
+    preds: FxHashMap<N, Vec<N>>,
+}
+
+impl<N, C> Search<N, C>
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord,
+{
+    pub fn start(&self) -> N {
+        self.start
+    }
+
+    pub fn dist(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &FxHashMap<N, C> {
+        &self.dist
+    }
+
+    /// Every node the search reached, including the start.
+    pub fn reached(&self) -> impl Iterator<Item = &N> {
+        self.dist.keys()
+    }
+
+    /// All predecessors of `node` that lie on an optimal path to it.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.preds.get(node).map_or(&[], |preds| preds.as_slice())
+    }
+
+    /// One optimal path from the start to `target`, both ends included.
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        self.dist.get(target)?;
+
+        let mut path = vec![*target];
+        while let Some(&pred) = self.predecessors(path.last().unwrap()).first() {
+            path.push(pred);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The number of distinct optimal paths from the start to `target`.
+    pub fn count_paths(&self, target: &N) -> u64 {
+        let Some(target_dist) = self.dist(target) else { return 0 };
+
+        let mut nodes = self.dist.iter()
+            .filter(|(_, &dist)| dist <= target_dist)
+            .collect::<Vec<_>>();
+        nodes.sort_by_key(|(_, &dist)| dist);
+
+        let mut counts = FxHashMap::default();
+        for (node, _) in nodes {
+            let count = if *node == self.start {
+                1
+            } else {
+                self.predecessors(node).iter()
+                    .map(|pred| counts.get(pred).copied().unwrap_or(0))
+                    .sum()
+            };
+            counts.insert(*node, count);
+        }
+
+        counts.get(target).copied().unwrap_or(0)
+    }
+
+    /// Every node lying on at least one optimal path to any of `targets`.
+    pub fn nodes_on_paths(&self, targets: impl IntoIterator<Item = N>) -> FxHashSet<N> {
+        let mut seen = FxHashSet::default();
+        let mut to_visit = targets.into_iter()
+            .filter(|target| self.dist.contains_key(target))
+            .collect::<Vec<_>>();
+
+        while let Some(node) = to_visit.pop() {
+            if seen.insert(node) {
+                to_visit.extend_from_slice(self.predecessors(&node));
+            }
+        }
+
+        seen
+    }
+}
+
+
+/// Breadth-first search where every edge costs one step.
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Search<N, usize>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut dist = FxHashMap::default();
+    let mut preds: FxHashMap<N, Vec<N>> = FxHashMap::default();
+    let mut to_visit = VecDeque::new();
+
+    dist.insert(start, 0);
+    to_visit.push_back(start);
+
+    while let Some(node) = to_visit.pop_front() {
+        let new_dist = dist[&node] + 1;
+        for neighbor in neighbors(&node) {
+            match dist.get(&neighbor) {
+                None => {
+                    dist.insert(neighbor, new_dist);
+                    preds.insert(neighbor, vec![node]);
+                    to_visit.push_back(neighbor);
+                },
+                Some(&d) if d == new_dist => preds.entry(neighbor).or_default().push(node),
+                Some(_) => (),
+            }
+        }
+    }
+
+    Search { start, dist, preds }
+}
+
+
+/// Dijkstra's algorithm over `(neighbor, edge cost)` pairs. Edge costs must
+/// be positive.
+pub fn dijkstra<N, C, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Search<N, C>
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut dist = FxHashMap::default();
+    let mut preds: FxHashMap<N, Vec<N>> = FxHashMap::default();
+    let mut frontier = BinaryHeap::new();
+
+    dist.insert(start, C::default());
+    frontier.push(Entry { priority: C::default(), cost: C::default(), node: start });
+
+    while let Some(Entry { cost, node, .. }) = frontier.pop() {
+        if dist[&node] < cost { continue }
+
+        for (neighbor, edge_cost) in neighbors(&node) {
+            let new_cost = cost + edge_cost;
+            match dist.get(&neighbor) {
+                Some(&d) if d < new_cost => (),
+                Some(&d) if d == new_cost => preds.entry(neighbor).or_default().push(node),
+                _ => {
+                    dist.insert(neighbor, new_cost);
+                    preds.insert(neighbor, vec![node]);
+                    frontier.push(Entry { priority: new_cost, cost: new_cost, node: neighbor });
+                },
+            }
+        }
+    }
+
+    Search { start, dist, preds }
+}
+
+
+/// A* search from `start` to the first node satisfying `is_goal`. The
+/// heuristic must never overestimate the remaining cost. Returns the path,
+/// both ends included, and its cost.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut dist = FxHashMap::default();
+    let mut preds: FxHashMap<N, N> = FxHashMap::default();
+    let mut frontier = BinaryHeap::new();
+
+    dist.insert(start, C::default());
+    frontier.push(Entry { priority: heuristic(&start), cost: C::default(), node: start });
+
+    while let Some(Entry { cost, node, .. }) = frontier.pop() {
+        if dist[&node] < cost { continue }
+
+        if is_goal(&node) {
+            let mut path = vec![node];
+            while let Some(pred) = preds.get(path.last().unwrap()) {
+                path.push(*pred);
+            }
+            path.reverse();
+            return Some((path, cost))
+        }
+
+        for (neighbor, edge_cost) in neighbors(&node) {
+            let new_cost = cost + edge_cost;
+            if dist.get(&neighbor).is_none_or(|&d| new_cost < d) {
+                dist.insert(neighbor, new_cost);
+                preds.insert(neighbor, node);
+                let priority = new_cost + heuristic(&neighbor);
+                frontier.push(Entry { priority, cost: new_cost, node: neighbor });
+            }
+        }
+    }
+
+    None
+}
+
+
+/// A frontier entry, ordered so the lowest priority pops first from a
+/// max-heap.
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 0 -> 1 -> 3 and 0 -> 2 -> 3, plus a slow 0 -> 3 edge.
+    fn diamond(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 | 2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs(0_i32, |&n| [n - 1, n + 1].into_iter().filter(|n| (0..10).contains(n)));
+        assert_eq!(search.dist(&9), Some(9));
+        assert_eq!(search.path_to(&3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(search.reached().count(), 10);
+        assert_eq!(search.count_paths(&9), 1);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(0, diamond);
+        assert_eq!(search.dist(&3), Some(2));
+        assert_eq!(search.predecessors(&3).len(), 2);
+        assert_eq!(search.count_paths(&3), 2);
+        assert_eq!(search.nodes_on_paths([3]).len(), 4);
+    }
+
+    #[test]
+    fn test_astar() {
+        let (path, cost) = astar(0, diamond, |_| 0, |&n| n == 3).unwrap();
+        assert_eq!(cost, 2);
+        assert_eq!(path.len(), 3);
+        assert!(astar(1, diamond, |_| 0, |&n| n == 0).is_none());
+    }
+}
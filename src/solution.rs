@@ -0,0 +1,66 @@
+use std::ops::RangeInclusive;
+
+use crate::answer::Answer;
+use crate::examples::Example;
+use crate::generate::{Generated, Rng};
+use crate::params::Params;
+use crate::parse::ParseError;
+
+/// A single day's puzzle, split into the parse step and the two parts.
+///
+/// The input is parsed once and both parts borrow the result, so parse time
+/// is reported separately from the time spent solving each part.
+///
+/// Each `day_N` module exposes a unit struct implementing this trait, which is
+/// then registered in [`crate::DAYS`] so the `aoc` runner can dispatch to it.
+pub trait Solution {
+    /// The structured form of the puzzle input produced by [`Solution::parse`].
+    /// Shared with the thread each part runs on when it has a time budget.
+    type Parsed: Sync;
+
+    /// The worked examples from the puzzle text, with their known answers.
+    /// Each day's tests check these through [`crate::examples::assert_pass`].
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// Named parameters the parts read through [`Params::get`], with their
+    /// values for the real puzzle input.
+    const PARAMS: &'static [(&'static str, i64)] = &[];
+
+    /// The values a parameter can take where not every integer makes sense,
+    /// such as a grid size of at least one. Overrides outside are rejected.
+    const PARAM_RANGES: &'static [(&'static str, RangeInclusive<i64>)] = &[];
+
+    /// Parameters small enough for the reference solvers to finish, applied
+    /// over those of each generated input when cross-checking.
+    const REFERENCE_PARAMS: &'static [(&'static str, i64)] = &[];
+
+    /// The declared parameters at their defaults, limited to their ranges.
+    fn default_params() -> Params {
+        Params::new(Self::PARAMS).with_ranges(Self::PARAM_RANGES)
+    }
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(input: &Self::Parsed, params: &Params) -> Answer;
+
+    fn part2(input: &Self::Parsed, params: &Params) -> Answer;
+
+    /// A random input in the puzzle's format for the `generate` command,
+    /// about the size of the worked example at `scale` 1 and growing with
+    /// it. Days without a generator return `None`.
+    fn generate(_rng: &mut Rng, _scale: u32) -> Option<Generated> {
+        None
+    }
+
+    /// A slow but plainly correct version of part 1, following the puzzle
+    /// text as directly as possible, which [`crate::differential`] checks
+    /// the real one against on generated inputs. `None` if there is none.
+    fn reference_part1(_input: &Self::Parsed, _params: &Params) -> Option<Answer> {
+        None
+    }
+
+    /// Like [`Solution::reference_part1`], for part 2.
+    fn reference_part2(_input: &Self::Parsed, _params: &Params) -> Option<Answer> {
+        None
+    }
+}
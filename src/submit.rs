@@ -0,0 +1,377 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::client::{Client, ClientError, Http};
+
+/// Directory holding every answer submitted for each day.
+pub const ATTEMPT_DIR: &str = "attempts";
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after a previous answer; try again after `wait`.
+    RateLimited { wait: Duration },
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Reads the outcome from the page returned for a submission.
+    pub fn parse(page: &str) -> Option<Self> {
+        let outcome = if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("You gave an answer too recently") {
+            Outcome::RateLimited { wait: parse_wait(page).unwrap_or(Duration::from_secs(60)) }
+        } else if page.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Incorrect
+            }
+        } else {
+            return None
+        };
+        Some(outcome)
+    }
+
+    /// Whether the answer is known to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow)
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::RateLimited { .. } => "rate-limited",
+            Outcome::WrongLevel => "wrong-level",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        let outcome = match key {
+            "correct" => Outcome::Correct,
+            "incorrect" => Outcome::Incorrect,
+            "too-high" => Outcome::TooHigh,
+            "too-low" => Outcome::TooLow,
+            "rate-limited" => Outcome::RateLimited { wait: Duration::ZERO },
+            "wrong-level" => Outcome::WrongLevel,
+            _ => return None,
+        };
+        Some(outcome)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::TooHigh => write!(f, "incorrect, too high"),
+            Outcome::TooLow => write!(f, "incorrect, too low"),
+            Outcome::RateLimited { wait } => write!(f, "rate limited, try again in {}s", wait.as_secs()),
+            Outcome::WrongLevel => write!(f, "not accepted, the part is already solved or still locked"),
+        }
+    }
+}
+
+
+/// Reads the wait from "you have 1m 5s left to wait".
+fn parse_wait(page: &str) -> Option<Duration> {
+    let (before, _) = page.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("You have ").or_else(|| before.rsplit_once("you have "))?;
+
+    let mut secs = 0;
+    for unit in wait.split_whitespace() {
+        let (value, scale) = if let Some(value) = unit.strip_suffix('h') {
+            (value, 3600)
+        } else if let Some(value) = unit.strip_suffix('m') {
+            (value, 60)
+        } else {
+            (unit.strip_suffix('s')?, 1)
+        };
+        secs += value.parse::<u64>().ok()? * scale;
+    }
+    Some(Duration::from_secs(secs))
+}
+
+
+/// One submitted answer and how it was judged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Why an answer won't be submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect,
+    /// The part is solved with a different answer.
+    Solved { answer: String },
+    KnownWrong,
+    /// A number outside the range left by earlier too high/low answers.
+    OutOfBounds { low: Option<i128>, high: Option<i128> },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect => write!(f, "this answer was already accepted"),
+            Refusal::Solved { answer } => write!(f, "already solved with {answer}"),
+            Refusal::KnownWrong => write!(f, "this answer was already rejected"),
+            Refusal::OutOfBounds { low, high } => {
+                write!(f, "the answer must be")?;
+                if let Some(low) = low {
+                    write!(f, " above {low}")?;
+                }
+                if let (Some(_), Some(_)) = (low, high) {
+                    write!(f, " and")?;
+                }
+                if let Some(high) = high {
+                    write!(f, " below {high}")?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+/// Every answer submitted for one day, stored as `part1 <outcome>: <answer>`
+/// lines in submission order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut attempts = Vec::new();
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() { continue }
+
+            let attempt = line.split_once(':')
+                .and_then(|(key, answer)| {
+                    let (part, outcome) = key.split_once(' ')?;
+                    let part = match part {
+                        "part1" => 1,
+                        "part2" => 2,
+                        _ => return None,
+                    };
+                    let outcome = Outcome::from_key(outcome.trim())?;
+                    Some(Attempt { part, answer: answer.trim().to_string(), outcome })
+                })
+                .ok_or_else(|| format!("line {}: expected `partN <outcome>: <answer>`", idx+1))?;
+            attempts.push(attempt);
+        }
+        Ok(Self { attempts })
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Checks an answer against earlier attempts at the same part.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Refusal> {
+        let attempts = self.attempts.iter()
+            .filter(|attempt| attempt.part == part)
+            .collect::<Vec<_>>();
+
+        if let Some(correct) = attempts.iter().find(|attempt| attempt.outcome == Outcome::Correct) {
+            if correct.answer == answer {
+                return Err(Refusal::AlreadyCorrect)
+            }
+            return Err(Refusal::Solved { answer: correct.answer.clone() })
+        }
+        if attempts.iter().any(|attempt| attempt.outcome.is_wrong() && attempt.answer == answer) {
+            return Err(Refusal::KnownWrong)
+        }
+
+        let Ok(value) = answer.parse::<i128>() else { return Ok(()) };
+        let bound = |outcome| attempts.iter()
+            .filter(move |attempt| attempt.outcome == outcome)
+            .filter_map(|attempt| attempt.answer.parse::<i128>().ok());
+        let low = bound(Outcome::TooLow).max();
+        let high = bound(Outcome::TooHigh).min();
+        if low.is_some_and(|low| value <= low) || high.is_some_and(|high| value >= high) {
+            return Err(Refusal::OutOfBounds { low, high })
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for attempt in self.attempts.iter() {
+            writeln!(f, "part{} {}: {}", attempt.part, attempt.outcome.key(), attempt.answer)?;
+        }
+        Ok(())
+    }
+}
+
+
+pub fn path(dir: &Path, day: u32) -> PathBuf {
+    dir.join(format!("day_{day}.txt"))
+}
+
+
+/// Loads a day's attempts from `dir`, treating a missing file as none yet.
+pub fn load(dir: &Path, day: u32) -> io::Result<History> {
+    let path = path(dir, day);
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(History::default()),
+        Err(err) => return Err(err),
+    };
+    History::parse(&contents)
+        .map_err(|msg| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {msg}", path.display())))
+}
+
+
+pub fn save(dir: &Path, day: u32, history: &History) -> io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(path(dir, day), history.to_string())
+}
+
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Client(ClientError),
+    /// The site replied with a page we couldn't make sense of.
+    UnknownResponse,
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitting: {refusal}"),
+            SubmitError::Client(err) => write!(f, "{err}"),
+            SubmitError::UnknownResponse => write!(f, "couldn't read the outcome from the response"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<ClientError> for SubmitError {
+    fn from(err: ClientError) -> Self {
+        SubmitError::Client(err)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(err: io::Error) -> Self {
+        SubmitError::Client(ClientError::Io(err))
+    }
+}
+
+
+/// Submits an answer unless the day's history in `dir` rules it out, and
+/// records the attempt.
+pub fn submit<H: Http>(
+    client: &Client<H>,
+    dir: &Path,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, SubmitError> {
+    let mut history = load(dir, day)?;
+    history.check(part, answer).map_err(SubmitError::Refused)?;
+
+    let page = client.submit_answer(day, part, answer)?;
+    let outcome = Outcome::parse(&page).ok_or(SubmitError::UnknownResponse)?;
+    history.record(Attempt { part, answer: answer.to_string(), outcome: outcome.clone() });
+    save(dir, day, &history)?;
+    Ok(outcome)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::UreqHttp;
+    use crate::client::test::{stub_server, FakeHttp};
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  \
+        If you're stuck, make sure you're using the full input data.</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+    const CORRECT: &str = "<article><p>That's the right answer!  You are one gold star closer \
+        to finding the Chief Historian.</p></article>";
+
+    #[test]
+    fn test_outcome() {
+        assert_eq!(Outcome::parse(TOO_HIGH), Some(Outcome::TooHigh));
+        assert_eq!(Outcome::parse(TOO_RECENT), Some(Outcome::RateLimited { wait: Duration::from_secs(65) }));
+        assert_eq!(Outcome::parse(CORRECT), Some(Outcome::Correct));
+        assert_eq!(Outcome::parse("<html></html>"), None);
+    }
+
+    #[test]
+    fn test_history() {
+        let history = History::parse("part1 too-low: 10\npart1 too-high: 50\npart1 incorrect: abc\n").unwrap();
+        assert_eq!(History::parse(&history.to_string()).unwrap(), history);
+
+        assert_eq!(history.check(1, "30"), Ok(()));
+        assert_eq!(history.check(1, "abc"), Err(Refusal::KnownWrong));
+        assert_eq!(history.check(1, "50"), Err(Refusal::KnownWrong));
+        assert_eq!(history.check(1, "60"), Err(Refusal::OutOfBounds { low: Some(10), high: Some(50) }));
+        assert_eq!(history.check(1, "5"), Err(Refusal::OutOfBounds { low: Some(10), high: Some(50) }));
+        assert_eq!(history.check(2, "60"), Ok(()));
+
+        assert!(History::parse("part3 correct: 1").is_err());
+        assert!(History::parse("part1 maybe: 1").is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let dir = std::env::temp_dir().join(format!("aoc2024-submit-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let client = Client::new(FakeHttp::replying(&[(200, TOO_HIGH), (200, CORRECT)]), "http://aoc", Some("abc".to_string()))
+            .with_rate_limit(Duration::ZERO);
+
+        assert_eq!(submit(&client, &dir, 5, 2, "500").unwrap(), Outcome::TooHigh);
+        assert!(matches!(submit(&client, &dir, 5, 2, "600"), Err(SubmitError::Refused(Refusal::OutOfBounds { .. }))));
+        assert_eq!(submit(&client, &dir, 5, 2, "400").unwrap(), Outcome::Correct);
+        assert!(matches!(submit(&client, &dir, 5, 2, "400"), Err(SubmitError::Refused(Refusal::AlreadyCorrect))));
+
+        let requests = client.http().requests.borrow();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].url, "http://aoc/2024/day/5/answer");
+        assert_eq!(requests[0].form, [("level".to_string(), "2".to_string()), ("answer".to_string(), "500".to_string())]);
+        assert_eq!(load(&dir, 5).unwrap().to_string(), "part2 too-high: 500\npart2 correct: 400\n");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_stub_server() {
+        let dir = std::env::temp_dir().join(format!("aoc2024-submit-stub-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let (base_url, server) = stub_server(200, TOO_RECENT);
+        let client = Client::new(UreqHttp::new(), &base_url, Some("abc".to_string()));
+
+        let outcome = submit(&client, &dir, 1, 1, "11").unwrap();
+        assert_eq!(outcome, Outcome::RateLimited { wait: Duration::from_secs(65) });
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=1&answer=11"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
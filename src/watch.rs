@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::params::Params;
+use crate::runner::Day;
+
+/// How often the watched files are checked for changes, in milliseconds.
+pub const POLL_INTERVAL_MS: u64 = 500;
+
+/// The files a change to which should re-run `day`: everything in its module
+/// directory, such as `mod.rs` and the `example*` files, and its `input`,
+/// taken relative to `root` unless absolute.
+pub fn watched_paths(root: &Path, day: u32, input: &Path) -> Vec<PathBuf> {
+    let module_dir = root.join("src").join(format!("day_{day}"));
+    let mut paths = std::fs::read_dir(&module_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths.push(root.join(input));
+    paths
+}
+
+
+/// Modification times of the watched files, with `None` for missing ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stamps(BTreeMap<PathBuf, Option<SystemTime>>);
+
+impl Stamps {
+    pub fn read(paths: &[PathBuf]) -> Self {
+        Self(paths.iter()
+            .map(|path| (path.clone(), std::fs::metadata(path).and_then(|meta| meta.modified()).ok()))
+            .collect())
+    }
+
+    /// Files added, removed or modified since `earlier`.
+    pub fn changed_since(&self, earlier: &Stamps) -> Vec<PathBuf> {
+        let mut changed = self.0.iter()
+            .filter(|(path, stamp)| earlier.0.get(*path) != Some(stamp))
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        changed.extend(earlier.0.keys()
+            .filter(|path| !self.0.contains_key(*path))
+            .cloned());
+        changed
+    }
+}
+
+
+/// Answers from one run of a day, passed from the rebuilt binary back to the
+/// watcher as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub examples: Vec<ExampleResult>,
+    /// The answers for the real input, or why there are none.
+    pub parts: Result<Vec<(u8, String)>, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExampleResult {
+    pub example: String,
+    pub part: u8,
+    pub expected: String,
+    pub answer: Result<String, String>,
+}
+
+impl Snapshot {
+    /// Runs the examples, then the real input if it could be read.
+    pub fn take(day: &Day, input: Result<String, String>, params: &Params) -> Self {
+        let examples = day.examples().into_iter()
+            .map(|outcome| ExampleResult {
+                example: outcome.example.to_string(),
+                part: outcome.part,
+                expected: outcome.expected.to_string(),
+                answer: outcome.answer.map_err(|err| err.to_string()),
+            })
+            .collect();
+
+        let parts = input.and_then(|input| {
+            let report = day.run(&input, params).map_err(|err| err.to_string())?;
+            Ok(report.parts.into_iter().map(|part| (part.part, part.answer)).collect())
+        });
+
+        Self { examples, parts }
+    }
+
+    /// Describes this run, marking answers that differ from `previous`.
+    pub fn render(&self, previous: Option<&Snapshot>) -> String {
+        let mut out = String::new();
+
+        for example in self.examples.iter() {
+            let status = match &example.answer {
+                Ok(answer) if *answer == example.expected => format!("pass - got {answer}"),
+                Ok(answer) => format!("FAIL (expected {}) - got {answer}", example.expected),
+                Err(err) => format!("FAIL (expected {}) - {err}", example.expected),
+            };
+            let before = previous
+                .and_then(|prev| prev.examples.iter().find(|e| e.example == example.example && e.part == example.part))
+                .map(|e| &e.answer);
+            writeln!(out, "{} part {}: {status}{}", example.example, example.part, change(before, &example.answer)).unwrap();
+        }
+
+        match &self.parts {
+            Ok(parts) => {
+                for (part, answer) in parts {
+                    let before = previous
+                        .and_then(|prev| prev.parts.as_ref().ok())
+                        .and_then(|parts| parts.iter().find(|(p, _)| p == part))
+                        .map(|(_, answer)| Ok::<_, String>(answer.clone()));
+                    writeln!(out, "input part {part}: {answer}{}", change(before.as_ref(), &Ok(answer.clone()))).unwrap();
+                }
+            },
+            Err(err) => writeln!(out, "input: {err}").unwrap(),
+        }
+        out
+    }
+}
+
+
+/// A note on how an answer moved since the last run, if it did.
+fn change(before: Option<&Result<String, String>>, after: &Result<String, String>) -> String {
+    match (before, after) {
+        (None, _) => String::new(),
+        (Some(before), after) if before == after => String::new(),
+        (Some(Ok(before)), _) => format!("  (was {before})"),
+        (Some(Err(_)), _) => "  (was an error)".to_string(),
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::input;
+
+    fn snapshot(example_answer: &str, part1: &str) -> Snapshot {
+        Snapshot {
+            examples: vec![ExampleResult {
+                example: "example".to_string(),
+                part: 1,
+                expected: "11".to_string(),
+                answer: Ok(example_answer.to_string()),
+            }],
+            parts: Ok(vec![(1, part1.to_string()), (2, "31".to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let first = snapshot("10", "1000");
+        assert_eq!(
+            first.render(None),
+            "example part 1: FAIL (expected 11) - got 10\ninput part 1: 1000\ninput part 2: 31\n"
+        );
+
+        let second = snapshot("11", "1100");
+        assert_eq!(
+            second.render(Some(&first)),
+            "example part 1: pass - got 11  (was 10)\ninput part 1: 1100  (was 1000)\ninput part 2: 31\n"
+        );
+    }
+
+    #[test]
+    fn test_stamps() {
+        let dir = std::env::temp_dir().join(format!("aoc2024-watch-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src/day_3")).unwrap();
+        std::fs::write(dir.join("src/day_3/mod.rs"), "").unwrap();
+
+        let paths = watched_paths(&dir, 3, &input::default_path(3));
+        assert_eq!(paths, [dir.join("src/day_3/mod.rs"), dir.join("inputs/day_3.txt")]);
+        let before = Stamps::read(&paths);
+        assert!(Stamps::read(&paths).changed_since(&before).is_empty());
+
+        std::fs::create_dir_all(dir.join("inputs")).unwrap();
+        std::fs::write(dir.join("inputs/day_3.txt"), "mul(2,4)").unwrap();
+        assert_eq!(Stamps::read(&paths).changed_since(&before), [dir.join("inputs/day_3.txt")]);
+
+        std::fs::write(dir.join("src/day_3/example2"), "").unwrap();
+        let after = Stamps::read(&watched_paths(&dir, 3, &input::default_path(3)));
+        assert_eq!(after.changed_since(&before), [dir.join("inputs/day_3.txt"), dir.join("src/day_3/example2")]);
+
+        // A custom input is watched in place of the default one
+        let custom = dir.join("custom.txt");
+        let paths = watched_paths(&dir, 3, &custom);
+        assert_eq!(paths, [dir.join("src/day_3/example2"), dir.join("src/day_3/mod.rs"), custom.clone()]);
+        let before = Stamps::read(&paths);
+        std::fs::write(&custom, "mul(3,4)").unwrap();
+        std::fs::write(dir.join("inputs/day_3.txt"), "mul(5,6)").unwrap();
+        assert_eq!(Stamps::read(&paths).changed_since(&before), [custom]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}